@@ -6,7 +6,7 @@ use crate::{
     state::{Info, State},
     util::format_heading_as_setext::format_heading_as_setext,
 };
-use alloc::format;
+use alloc::{format, vec::Vec};
 use markdown::{
     mdast::{Heading, Node},
     message::Message,
@@ -73,6 +73,18 @@ impl Handle for Heading {
             value.push_str(&sequence);
         }
 
+        if self.id.is_some() || !self.classes.is_empty() {
+            value.push_str(" {");
+            let attributes = self
+                .id
+                .iter()
+                .map(|id| format!("#{}", id))
+                .chain(self.classes.iter().map(|class| format!(".{}", class)))
+                .collect::<Vec<_>>();
+            value.push_str(&attributes.join(" "));
+            value.push('}');
+        }
+
         state.exit();
         state.exit();
 
@@ -17,9 +17,12 @@ pub fn format_heading_as_setext(heading: &Heading, state: &State) -> bool {
         }
     }
 
+    // Attributes can only be written after a heading (atx).
+    let attributes = heading.id.is_some() || !heading.classes.is_empty();
+
     heading.depth < 3
         && !to_string(&heading.children).is_empty()
-        && (state.options.setext || literal_with_line_break)
+        && ((state.options.setext && !attributes) || literal_with_line_break)
 }
 
 /// See: <https://github.com/syntax-tree/mdast-util-to-markdown/blob/main/lib/util/format-heading-as-setext.js>.
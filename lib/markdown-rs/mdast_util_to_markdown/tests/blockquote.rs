@@ -132,7 +132,9 @@ fn block_quote() {
                                 position: None
                             })],
                             position: None,
-                            depth: 1
+                            depth: 1,
+                            id: None,
+                            classes: vec![]
                         })
                     ],
                     position: None
@@ -292,7 +294,9 @@ fn block_quote() {
                     position: None
                 }),],
                 position: None,
-                depth: 3
+                depth: 3,
+                id: None,
+                classes: vec![]
             })],
             position: None,
         }))
@@ -310,7 +314,9 @@ fn block_quote() {
                         position: None
                     }),],
                     position: None,
-                    depth: 1
+                    depth: 1,
+                    id: None,
+                    classes: vec![]
                 })],
                 position: None,
             }),
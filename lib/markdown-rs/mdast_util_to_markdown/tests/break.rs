@@ -29,7 +29,9 @@ fn r#break() {
                 }),
             ],
             position: None,
-            depth: 3
+            depth: 3,
+            id: None,
+            classes: vec![]
         }))
         .unwrap(),
         "### a b\n",
@@ -50,7 +52,9 @@ fn r#break() {
                 }),
             ],
             position: None,
-            depth: 3
+            depth: 3,
+            id: None,
+            classes: vec![]
         }))
         .unwrap(),
         "### a b\n",
@@ -351,7 +351,9 @@ fn core() {
                         position: None
                     })],
                     position: None,
-                    depth: 1
+                    depth: 1,
+                    id: None,
+                    classes: vec![]
                 })
             ],
             position: None,
@@ -380,7 +382,9 @@ fn core() {
                             position: None
                         })],
                         position: None,
-                        depth: 1
+                        depth: 1,
+                        id: None,
+                        classes: vec![]
                     })
                 ],
                 position: None,
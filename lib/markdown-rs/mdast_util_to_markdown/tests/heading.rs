@@ -10,7 +10,9 @@ fn heading() {
         to(&Node::Heading(Heading {
             children: vec![],
             position: None,
-            depth: 1
+            depth: 1,
+            id: None,
+            classes: vec![]
         }))
         .unwrap(),
         "#\n",
@@ -21,7 +23,9 @@ fn heading() {
         to(&Node::Heading(Heading {
             children: vec![],
             position: None,
-            depth: 6
+            depth: 6,
+            id: None,
+            classes: vec![]
         }))
         .unwrap(),
         "######\n",
@@ -32,7 +36,9 @@ fn heading() {
         to(&Node::Heading(Heading {
             children: vec![],
             position: None,
-            depth: 7
+            depth: 7,
+            id: None,
+            classes: vec![]
         }))
         .unwrap(),
         "######\n",
@@ -43,7 +49,9 @@ fn heading() {
         to(&Node::Heading(Heading {
             children: vec![],
             position: None,
-            depth: 0
+            depth: 0,
+            id: None,
+            classes: vec![]
         }))
         .unwrap(),
         "#\n",
@@ -57,7 +65,9 @@ fn heading() {
                 position: None
             })],
             position: None,
-            depth: 1
+            depth: 1,
+            id: None,
+            classes: vec![]
         }))
         .unwrap(),
         "# a\n",
@@ -72,7 +82,9 @@ fn heading() {
                     position: None
                 })],
                 position: None,
-                depth: 1
+                depth: 1,
+                id: None,
+                classes: vec![]
             }),
             &Options {
                 setext: true,
@@ -92,7 +104,9 @@ fn heading() {
                     position: None
                 })],
                 position: None,
-                depth: 2
+                depth: 2,
+                id: None,
+                classes: vec![]
             }),
             &Options {
                 setext: true,
@@ -112,7 +126,9 @@ fn heading() {
                     position: None
                 })],
                 position: None,
-                depth: 3
+                depth: 3,
+                id: None,
+                classes: vec![]
             }),
             &Options {
                 setext: true,
@@ -132,7 +148,9 @@ fn heading() {
                     position: None
                 })],
                 position: None,
-                depth: 2
+                depth: 2,
+                id: None,
+                classes: vec![]
             }),
             &Options {
                 setext: true,
@@ -152,7 +170,9 @@ fn heading() {
                     position: None
                 })],
                 position: None,
-                depth: 1
+                depth: 1,
+                id: None,
+                classes: vec![]
             }),
             &Options {
                 setext: true,
@@ -169,7 +189,9 @@ fn heading() {
             &Node::Heading(Heading {
                 children: vec![],
                 position: None,
-                depth: 1
+                depth: 1,
+                id: None,
+                classes: vec![]
             }),
             &Options {
                 setext: true,
@@ -186,7 +208,9 @@ fn heading() {
             &Node::Heading(Heading {
                 children: vec![],
                 position: None,
-                depth: 2
+                depth: 2,
+                id: None,
+                classes: vec![]
             }),
             &Options {
                 setext: true,
@@ -216,7 +240,9 @@ fn heading() {
                 position: None
             })],
             position: None,
-            depth: 1
+            depth: 1,
+            id: None,
+            classes: vec![]
         }),)
         .unwrap(),
         "<a\n/>\n==\n",
@@ -230,7 +256,9 @@ fn heading() {
                 position: None
             })],
             position: None,
-            depth: 1
+            depth: 1,
+            id: None,
+            classes: vec![]
         }))
         .unwrap(),
         "a\nb\n=\n",
@@ -251,7 +279,9 @@ fn heading() {
                 }),
             ],
             position: None,
-            depth: 1
+            depth: 1,
+            id: None,
+            classes: vec![]
         }))
         .unwrap(),
         "a\\\nb\n=\n",
@@ -263,7 +293,9 @@ fn heading() {
             &Node::Heading(Heading {
                 children: vec![],
                 position: None,
-                depth: 1
+                depth: 1,
+                id: None,
+                classes: vec![]
             }),
             &Options {
                 close_atx: true,
@@ -283,7 +315,9 @@ fn heading() {
                     position: None
                 })],
                 position: None,
-                depth: 3
+                depth: 3,
+                id: None,
+                classes: vec![]
             }),
             &Options {
                 close_atx: true,
@@ -302,7 +336,9 @@ fn heading() {
                 position: None
             })],
             position: None,
-            depth: 2
+            depth: 2,
+            id: None,
+            classes: vec![]
         }))
         .unwrap(),
         "## # a\n",
@@ -316,7 +352,9 @@ fn heading() {
                 position: None
             })],
             position: None,
-            depth: 2
+            depth: 2,
+            id: None,
+            classes: vec![]
         }))
         .unwrap(),
         "## 1) a\n",
@@ -330,7 +368,9 @@ fn heading() {
                 position: None
             })],
             position: None,
-            depth: 2
+            depth: 2,
+            id: None,
+            classes: vec![]
         }))
         .unwrap(),
         "## + a\n",
@@ -344,7 +384,9 @@ fn heading() {
                 position: None
             })],
             position: None,
-            depth: 2
+            depth: 2,
+            id: None,
+            classes: vec![]
         }))
         .unwrap(),
         "## - a\n",
@@ -358,7 +400,9 @@ fn heading() {
                 position: None
             })],
             position: None,
-            depth: 2
+            depth: 2,
+            id: None,
+            classes: vec![]
         }))
         .unwrap(),
         "## = a\n",
@@ -372,7 +416,9 @@ fn heading() {
                 position: None
             })],
             position: None,
-            depth: 2
+            depth: 2,
+            id: None,
+            classes: vec![]
         }))
         .unwrap(),
         "## > a\n",
@@ -386,7 +432,9 @@ fn heading() {
                 position: None
             })],
             position: None,
-            depth: 1
+            depth: 1,
+            id: None,
+            classes: vec![]
         }))
         .unwrap(),
         "# a \\#\n",
@@ -400,7 +448,9 @@ fn heading() {
                 position: None
             })],
             position: None,
-            depth: 1
+            depth: 1,
+            id: None,
+            classes: vec![]
         }))
         .unwrap(),
         "# a #\\#\n",
@@ -414,7 +464,9 @@ fn heading() {
                 position: None
             })],
             position: None,
-            depth: 1
+            depth: 1,
+            id: None,
+            classes: vec![]
         }))
         .unwrap(),
         "# a # b\n",
@@ -428,7 +480,9 @@ fn heading() {
                 position: None
             })],
             position: None,
-            depth: 1
+            depth: 1,
+            id: None,
+            classes: vec![]
         }))
         .unwrap(),
         "# &#x20; a\n",
@@ -442,7 +496,9 @@ fn heading() {
                 position: None
             })],
             position: None,
-            depth: 1
+            depth: 1,
+            id: None,
+            classes: vec![]
         }))
         .unwrap(),
         "# &#x9;\ta\n",
@@ -456,7 +512,9 @@ fn heading() {
                 position: None
             })],
             position: None,
-            depth: 1
+            depth: 1,
+            id: None,
+            classes: vec![]
         }))
         .unwrap(),
         "# a &#x20;\n",
@@ -470,7 +528,9 @@ fn heading() {
                 position: None
             })],
             position: None,
-            depth: 1
+            depth: 1,
+            id: None,
+            classes: vec![]
         }))
         .unwrap(),
         "# a\t&#x9;\n",
@@ -484,7 +544,9 @@ fn heading() {
                 position: None
             })],
             position: None,
-            depth: 1
+            depth: 1,
+            id: None,
+            classes: vec![]
         }))
         .unwrap(),
         "a&#x20;\n&#x20;b\n=======\n",
@@ -498,10 +560,50 @@ fn heading() {
                 position: None
             })],
             position: None,
-            depth: 3
+            depth: 3,
+            id: None,
+            classes: vec![]
         }))
         .unwrap(),
         "### a &#xA; b\n",
         "should not need to encode spaces around a line ending in an atx heading (because the line ending is encoded)"
     );
+
+    assert_eq!(
+        to(&Node::Heading(Heading {
+            children: vec![Node::Text(Text {
+                value: String::from("a"),
+                position: None
+            })],
+            position: None,
+            depth: 2,
+            id: Some(String::from("b")),
+            classes: vec![String::from("c"), String::from("d")]
+        }))
+        .unwrap(),
+        "## a {#b .c .d}\n",
+        "should serialize attributes after a heading"
+    );
+
+    assert_eq!(
+        to_md_with_opts(
+            &Node::Heading(Heading {
+                children: vec![Node::Text(Text {
+                    value: String::from("a"),
+                    position: None
+                })],
+                position: None,
+                depth: 1,
+                id: None,
+                classes: vec![String::from("b")]
+            }),
+            &Options {
+                setext: true,
+                ..Default::default()
+            }
+        )
+        .unwrap(),
+        "# a {.b}\n",
+        "should serialize a heading w/ attributes as atx when `setext: true`"
+    );
 }
@@ -176,6 +176,13 @@ pub struct Constructs {
     ///   | b
     /// ```
     pub hard_break_trailing: bool,
+    /// Heading attributes, on heading (atx) only.
+    ///
+    /// ```markdown
+    /// > | # a {#b .c}
+    ///         ^^^^^^^
+    /// ```
+    pub heading_attributes: bool,
     /// Heading (atx).
     ///
     /// ```markdown
@@ -363,6 +370,7 @@ impl Default for Constructs {
             gfm_task_list_item: false,
             hard_break_escape: true,
             hard_break_trailing: true,
+            heading_attributes: false,
             heading_atx: true,
             heading_setext: true,
             html_flow: true,
@@ -992,6 +1000,44 @@ pub struct CompileOptions {
     /// * [*§ 6.1 Disallowed Raw HTML (extension)* in GFM](https://github.github.com/gfm/#disallowed-raw-html-extension-)
     /// * [`cmark-gfm#extensions/tagfilter.c`](https://github.com/github/cmark-gfm/blob/master/extensions/tagfilter.c)
     pub gfm_tagfilter: bool,
+
+    /// Configuration for
+    /// [`to_frankenui_document()`][crate::to_frankenui_document].
+    ///
+    /// The default keeps code (fenced) without badge and copy button and
+    /// headings without rule, and uses the classes of
+    /// [`FrankenUiClasses::default()`][FrankenUiClasses].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_frankenui_document, CompileOptions, FrankenUiClasses, FrankenUiOptions, Options};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// assert_eq!(
+    ///     to_frankenui_document(
+    ///         "## a",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               frankenui: FrankenUiOptions {
+    ///                 heading_rule: true,
+    ///                 classes: FrankenUiClasses {
+    ///                   heading_2: "uk-h2 mt-12".into(),
+    ///                   ..FrankenUiClasses::default()
+    ///                 },
+    ///                 ..FrankenUiOptions::default()
+    ///               },
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?.html,
+    ///     "<h2 class=\"uk-h2 mt-12\" id=\"a\">a</h2>\n<hr class=\"uk-hr mb-6\"/>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub frankenui: FrankenUiOptions,
 }
 
 impl CompileOptions {
@@ -1012,6 +1058,287 @@ impl CompileOptions {
     }
 }
 
+/// Configuration that describes how to compile to `FrankenUI` HTML, with
+/// [`to_frankenui_document()`][crate::to_frankenui_document].
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{FrankenUiClasses, FrankenUiOptions};
+/// # fn main() {
+///
+/// // Use the default trait to get the default classes:
+/// let options = FrankenUiOptions::default();
+///
+/// // Copy buttons and striped tables:
+/// let striped = FrankenUiOptions {
+///   code_copy_button: true,
+///   classes: FrankenUiClasses {
+///     table: "uk-table uk-table-divider uk-table-striped".into(),
+///     ..FrankenUiClasses::default()
+///   },
+///   ..FrankenUiOptions::default()
+/// };
+/// # }
+/// ```
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, rename_all = "camelCase")
+)]
+pub struct FrankenUiOptions {
+    /// Whether to show the language of code (fenced) in a badge.
+    ///
+    /// The default is `false`.
+    /// Pass `true` to add a badge above code (fenced) with a language.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_frankenui_document, CompileOptions, FrankenUiOptions, Options};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// assert_eq!(
+    ///     to_frankenui_document(
+    ///         "```rust\na\n```",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               frankenui: FrankenUiOptions {
+    ///                 code_language_badge: true,
+    ///                 ..FrankenUiOptions::default()
+    ///               },
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?.html,
+    ///     "<div class=\"relative mt-4\"><div class=\"absolute right-2 top-2 flex items-center gap-2\"><span class=\"uk-badge uk-badge-secondary\">rust</span></div><pre class=\"m-0\"><code class=\"language-rust\">a\n</code></pre></div>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub code_language_badge: bool,
+
+    /// Whether to add a button that copies code (fenced) to the clipboard.
+    ///
+    /// The default is `false`.
+    /// Pass `true` to add a button with a `data-copy-code` attribute above
+    /// code (fenced), for a script to copy the code when clicked.
+    /// Code (fenced) with `nocopy` in its meta gets no button.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_frankenui_document, CompileOptions, FrankenUiOptions, Options};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// assert_eq!(
+    ///     to_frankenui_document(
+    ///         "```\na\n```",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               frankenui: FrankenUiOptions {
+    ///                 code_copy_button: true,
+    ///                 ..FrankenUiOptions::default()
+    ///               },
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?.html,
+    ///     "<div class=\"relative mt-4\"><div class=\"absolute right-2 top-2 flex items-center gap-2\"><button type=\"button\" class=\"uk-btn uk-btn-default uk-btn-xs uk-btn-icon\" data-copy-code=\"\" aria-label=\"Copy code\"><uk-icon icon=\"copy\"></uk-icon></button></div><pre class=\"m-0\"><code>a\n</code></pre></div>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub code_copy_button: bool,
+
+    /// Whether to add a rule after headings of rank 1 and 2.
+    ///
+    /// The default is `true`, which adds an `<hr>` after `<h1>` and `<h2>`,
+    /// to set them apart from the sections below them.
+    /// Pass `false` to leave the rule out.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_frankenui_document, CompileOptions, FrankenUiOptions, Options};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` adds a rule by default:
+    /// assert_eq!(
+    ///     to_frankenui_document("## a", &Options::default())?.html,
+    ///     "<h2 class=\"uk-h2 mt-8 mb-4\" id=\"a\">a</h2>\n<hr class=\"uk-hr mb-6\"/>"
+    /// );
+    ///
+    /// // Pass `heading_rule: false` to leave it out:
+    /// assert_eq!(
+    ///     to_frankenui_document(
+    ///         "## a",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               frankenui: FrankenUiOptions {
+    ///                 heading_rule: false,
+    ///                 ..FrankenUiOptions::default()
+    ///               },
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?.html,
+    ///     "<h2 class=\"uk-h2 mt-8 mb-4\" id=\"a\">a</h2>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub heading_rule: bool,
+
+    /// Classes of the elements that are written.
+    pub classes: FrankenUiClasses,
+}
+
+/// Classes of the elements written by
+/// [`to_frankenui_document()`][crate::to_frankenui_document], one per element
+/// and variant.
+///
+/// Each is the value of a `class` attribute, such as `uk-list uk-list-disc
+/// mt-4`.
+/// An empty value leaves the attribute out.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, rename_all = "camelCase")
+)]
+pub struct FrankenUiClasses {
+    /// Paragraph (`<p>`).
+    pub paragraph: String,
+    /// Heading of rank 1 (`<h1>`).
+    pub heading_1: String,
+    /// Heading of rank 2 (`<h2>`).
+    pub heading_2: String,
+    /// Heading of rank 3 (`<h3>`).
+    pub heading_3: String,
+    /// Heading of rank 4 (`<h4>`).
+    pub heading_4: String,
+    /// Heading of rank 5 (`<h5>`).
+    pub heading_5: String,
+    /// Heading of rank 6 (`<h6>`).
+    pub heading_6: String,
+    /// Rule after headings of rank 1 and 2 (`<hr>`), see
+    /// [`FrankenUiOptions::heading_rule`].
+    pub heading_rule: String,
+    /// Thematic break (`<hr>`).
+    pub thematic_break: String,
+    /// Block quote (`<blockquote>`).
+    pub block_quote: String,
+    /// Ordered list (`<ol>`).
+    pub list_ordered: String,
+    /// Unordered list (`<ul>`).
+    pub list_unordered: String,
+    /// GFM task list item check (`<input>`).
+    pub task_list_item_check: String,
+    /// Emphasis (`<em>`).
+    pub emphasis: String,
+    /// Strong (`<strong>`).
+    pub strong: String,
+    /// GFM strikethrough (`<del>`).
+    pub strikethrough: String,
+    /// Code (text) (`<code>`).
+    pub code_text: String,
+    /// Link (`<a>`).
+    pub link: String,
+    /// Element around GFM tables, which scrolls wide tables (`<div>`).
+    pub table_wrapper: String,
+    /// GFM table (`<table>`).
+    pub table: String,
+    /// GFM table cell aligned left (`<th>`, `<td>`).
+    pub table_cell_left: String,
+    /// GFM table cell aligned right (`<th>`, `<td>`).
+    pub table_cell_right: String,
+    /// GFM table cell aligned center (`<th>`, `<td>`).
+    pub table_cell_center: String,
+    /// Code (fenced, indented): added to the outermost element, which is
+    /// `<pre>` when the code has no title, badge, or copy button.
+    pub code_block: String,
+    /// `<pre>` of code (fenced) inside a card or with a badge or copy
+    /// button.
+    pub code_block_inner: String,
+    /// Card around code (fenced) with a title (`<div>`).
+    pub code_block_card: String,
+    /// Title of code (fenced) (`<div>`).
+    pub code_block_title: String,
+    /// Toolbar with the badge and copy button of code (fenced) (`<div>`).
+    pub code_block_toolbar: String,
+    /// Language badge of code (fenced) (`<span>`).
+    pub code_block_badge: String,
+    /// Copy button of code (fenced) (`<button>`).
+    pub code_block_copy_button: String,
+    /// Line of code (fenced) with highlighted lines or line numbers
+    /// (`<span>`).
+    pub code_line: String,
+    /// Highlighted line of code (fenced) (`<span>`).
+    pub code_line_highlighted: String,
+    /// Line number of code (fenced) (`<span>`).
+    pub code_line_number: String,
+}
+
+impl Default for FrankenUiOptions {
+    /// No badges or copy buttons, rules after headings of rank 1 and 2, and
+    /// the default classes.
+    fn default() -> Self {
+        Self {
+            code_language_badge: false,
+            code_copy_button: false,
+            heading_rule: true,
+            classes: FrankenUiClasses::default(),
+        }
+    }
+}
+
+impl Default for FrankenUiClasses {
+    /// `FrankenUI` classes, with spacing from Tailwind utilities.
+    fn default() -> Self {
+        Self {
+            paragraph: "uk-paragraph".into(),
+            heading_1: "uk-h1 mt-8 mb-4".into(),
+            heading_2: "uk-h2 mt-8 mb-4".into(),
+            heading_3: "uk-h3 mt-8 mb-4".into(),
+            heading_4: "uk-h4 mt-8 mb-4".into(),
+            heading_5: "uk-h5 mt-8 mb-4".into(),
+            heading_6: "uk-h6 mt-8 mb-4".into(),
+            heading_rule: "uk-hr mb-6".into(),
+            thematic_break: "uk-divider-icon mt-4".into(),
+            block_quote: "uk-blockquote mt-4".into(),
+            list_ordered: "uk-list uk-list-decimal mt-4".into(),
+            list_unordered: "uk-list uk-list-disc mt-4".into(),
+            task_list_item_check: "uk-checkbox".into(),
+            emphasis: "italic".into(),
+            strong: "font-bold".into(),
+            strikethrough: "line-through".into(),
+            code_text: "uk-codespan".into(),
+            link: "uk-link".into(),
+            table_wrapper: "uk-overflow-auto mt-4".into(),
+            table: "uk-table uk-table-divider".into(),
+            table_cell_left: "uk-text-left".into(),
+            table_cell_right: "uk-text-right".into(),
+            table_cell_center: "uk-text-center".into(),
+            code_block: "mt-4".into(),
+            code_block_inner: "m-0".into(),
+            code_block_card: "uk-card".into(),
+            code_block_title: "uk-card-header px-4 py-2 font-mono text-sm".into(),
+            code_block_toolbar: "absolute right-2 top-2 flex items-center gap-2".into(),
+            code_block_badge: "uk-badge uk-badge-secondary".into(),
+            code_block_copy_button: "uk-btn uk-btn-default uk-btn-xs uk-btn-icon".into(),
+            code_line: "code-line".into(),
+            code_line_highlighted: "code-line code-line-highlighted".into(),
+            code_line_number: "code-line-number".into(),
+        }
+    }
+}
+
 /// Configuration that describes how to parse from markdown.
 ///
 /// You can use this:
@@ -1456,7 +1783,7 @@ mod tests {
 
         assert_eq!(
             format!("{:?}", ParseOptions::default()),
-            "ParseOptions { constructs: Constructs { attention: true, autolink: true, block_quote: true, character_escape: true, character_reference: true, code_indented: true, code_fenced: true, code_text: true, definition: true, frontmatter: false, gfm_autolink_literal: false, gfm_footnote_definition: false, gfm_label_start_footnote: false, gfm_strikethrough: false, gfm_table: false, gfm_task_list_item: false, hard_break_escape: true, hard_break_trailing: true, heading_atx: true, heading_setext: true, html_flow: true, html_text: true, label_start_image: true, label_start_link: true, label_end: true, list_item: true, math_flow: false, math_text: false, mdx_esm: false, mdx_expression_flow: false, mdx_expression_text: false, mdx_jsx_flow: false, mdx_jsx_text: false, thematic_break: true }, gfm_strikethrough_single_tilde: true, math_text_single_dollar: true, mdx_expression_parse: None, mdx_esm_parse: None }",
+            "ParseOptions { constructs: Constructs { attention: true, autolink: true, block_quote: true, character_escape: true, character_reference: true, code_indented: true, code_fenced: true, code_text: true, definition: true, frontmatter: false, gfm_autolink_literal: false, gfm_footnote_definition: false, gfm_label_start_footnote: false, gfm_strikethrough: false, gfm_table: false, gfm_task_list_item: false, hard_break_escape: true, hard_break_trailing: true, heading_attributes: false, heading_atx: true, heading_setext: true, html_flow: true, html_text: true, label_start_image: true, label_start_link: true, label_end: true, list_item: true, math_flow: false, math_text: false, mdx_esm: false, mdx_expression_flow: false, mdx_expression_text: false, mdx_jsx_flow: false, mdx_jsx_text: false, thematic_break: true }, gfm_strikethrough_single_tilde: true, math_text_single_dollar: true, mdx_expression_parse: None, mdx_esm_parse: None }",
             "should support `Debug` trait"
         );
         assert_eq!(
@@ -1469,7 +1796,7 @@ mod tests {
                 })),
                 ..Default::default()
             }),
-            "ParseOptions { constructs: Constructs { attention: true, autolink: true, block_quote: true, character_escape: true, character_reference: true, code_indented: true, code_fenced: true, code_text: true, definition: true, frontmatter: false, gfm_autolink_literal: false, gfm_footnote_definition: false, gfm_label_start_footnote: false, gfm_strikethrough: false, gfm_table: false, gfm_task_list_item: false, hard_break_escape: true, hard_break_trailing: true, heading_atx: true, heading_setext: true, html_flow: true, html_text: true, label_start_image: true, label_start_link: true, label_end: true, list_item: true, math_flow: false, math_text: false, mdx_esm: false, mdx_expression_flow: false, mdx_expression_text: false, mdx_jsx_flow: false, mdx_jsx_text: false, thematic_break: true }, gfm_strikethrough_single_tilde: true, math_text_single_dollar: true, mdx_expression_parse: Some(\"[Function]\"), mdx_esm_parse: Some(\"[Function]\") }",
+            "ParseOptions { constructs: Constructs { attention: true, autolink: true, block_quote: true, character_escape: true, character_reference: true, code_indented: true, code_fenced: true, code_text: true, definition: true, frontmatter: false, gfm_autolink_literal: false, gfm_footnote_definition: false, gfm_label_start_footnote: false, gfm_strikethrough: false, gfm_table: false, gfm_task_list_item: false, hard_break_escape: true, hard_break_trailing: true, heading_attributes: false, heading_atx: true, heading_setext: true, html_flow: true, html_text: true, label_start_image: true, label_start_link: true, label_end: true, list_item: true, math_flow: false, math_text: false, mdx_esm: false, mdx_expression_flow: false, mdx_expression_text: false, mdx_jsx_flow: false, mdx_jsx_text: false, thematic_break: true }, gfm_strikethrough_single_tilde: true, math_text_single_dollar: true, mdx_expression_parse: Some(\"[Function]\"), mdx_esm_parse: Some(\"[Function]\") }",
             "should support `Debug` trait on mdx functions"
         );
     }
@@ -0,0 +1,232 @@
+//! Heading attributes occur at the end of [heading (atx)][heading_atx].
+//!
+//! ## Grammar
+//!
+//! Heading attributes form with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! ; Restriction: must be followed by `*space_or_tab eol`.
+//! heading_attributes ::= '{' *space_or_tab attribute *(1*space_or_tab attribute) *space_or_tab '}'
+//! attribute ::= ('#' | '.') 1*name_byte
+//! name_byte ::= ascii_alphanumeric | '-' | '_' | ':'
+//! ```
+//!
+//! A `#` attribute sets the identifier of the heading, and a `.` attribute
+//! adds a class to it.
+//! When there are several identifiers, the last one is used.
+//!
+//! Attributes must come at the end of the line, after the text and the
+//! closing sequence, if any (`## a ## {#b}`).
+//! Anything else that looks like attributes, such as `{#a} b` or `{a}`, is
+//! part of the text of the heading.
+//!
+//! Only [heading (atx)][heading_atx] supports attributes.
+//! On a [heading (setext)][heading_setext], such as `a {#b}` followed by
+//! `=`, they are part of the text of the heading.
+//!
+//! ## HTML
+//!
+//! Heading attributes relate to the `id` and `class` attributes in HTML.
+//! See [*§ 3.2.6 Global attributes* in the HTML spec][html] for more info.
+//!
+//! They are honored when compiling to `FrankenUI` HTML, where the identifier
+//! replaces the slug generated from the text.
+//! An identifier that an earlier heading already has gets a numeric suffix
+//! (`a-1`), just like a generated slug would.
+//! They are ignored when compiling to plain HTML.
+//!
+//! ## Recommendation
+//!
+//! Use an identifier for headings that are linked to from elsewhere, so that
+//! links keep working when the text of the heading changes.
+//!
+//! ## Tokens
+//!
+//! * [`HeadingAttributes`][Name::HeadingAttributes]
+//! * [`HeadingAttributesClass`][Name::HeadingAttributesClass]
+//! * [`HeadingAttributesId`][Name::HeadingAttributesId]
+//! * [`HeadingAttributesMarker`][Name::HeadingAttributesMarker]
+//! * [`SpaceOrTab`][Name::SpaceOrTab]
+//!
+//! ## References
+//!
+//! * [*§ Extension: `header_attributes`* in Pandoc](https://pandoc.org/MANUAL.html#extension-header_attributes)
+//!
+//! [heading_atx]: crate::construct::heading_atx
+//! [heading_setext]: crate::construct::heading_setext
+//! [html]: https://html.spec.whatwg.org/multipage/dom.html#global-attributes
+
+use crate::construct::partial_space_or_tab::space_or_tab;
+use crate::event::Name;
+use crate::state::{Name as StateName, State};
+use crate::tokenizer::Tokenizer;
+
+/// At start of heading attributes.
+///
+/// ```markdown
+/// > | ## a {#b .c}
+///          ^
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.parse_state.options.constructs.heading_attributes
+        && tokenizer.current == Some(b'{')
+    {
+        tokenizer.enter(Name::HeadingAttributes);
+        tokenizer.enter(Name::HeadingAttributesMarker);
+        tokenizer.consume();
+        tokenizer.exit(Name::HeadingAttributesMarker);
+        State::Next(StateName::HeadingAttributesOpenAfter)
+    } else {
+        State::Nok
+    }
+}
+
+/// After `{`, at optional whitespace.
+///
+/// ```markdown
+/// > | ## a {#b .c}
+///           ^
+/// ```
+pub fn open_after(tokenizer: &mut Tokenizer) -> State {
+    if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+        tokenizer.attempt(
+            State::Next(StateName::HeadingAttributesAttributeBefore),
+            State::Nok,
+        );
+        State::Retry(space_or_tab(tokenizer))
+    } else {
+        State::Retry(StateName::HeadingAttributesAttributeBefore)
+    }
+}
+
+/// Before an attribute, at `#` or `.`.
+///
+/// ```markdown
+/// > | ## a {#b .c}
+///           ^  ^
+/// ```
+pub fn attribute_before(tokenizer: &mut Tokenizer) -> State {
+    let token = match tokenizer.current {
+        Some(b'#') => Name::HeadingAttributesId,
+        Some(b'.') => Name::HeadingAttributesClass,
+        _ => return State::Nok,
+    };
+
+    tokenizer.tokenize_state.token_1 = token;
+    tokenizer.enter(Name::HeadingAttributesMarker);
+    tokenizer.consume();
+    tokenizer.exit(Name::HeadingAttributesMarker);
+    State::Next(StateName::HeadingAttributesNameStart)
+}
+
+/// After `#` or `.`, at the name of an attribute.
+///
+/// ```markdown
+/// > | ## a {#b .c}
+///            ^  ^
+/// ```
+pub fn name_start(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current.map_or(false, is_name_byte) {
+        tokenizer.enter(tokenizer.tokenize_state.token_1.clone());
+        State::Retry(StateName::HeadingAttributesName)
+    } else {
+        tokenizer.tokenize_state.token_1 = Name::Data;
+        State::Nok
+    }
+}
+
+/// In the name of an attribute.
+///
+/// ```markdown
+/// > | ## a {#b .c}
+///            ^  ^
+/// ```
+pub fn name(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current.map_or(false, is_name_byte) {
+        tokenizer.consume();
+        State::Next(StateName::HeadingAttributesName)
+    } else {
+        tokenizer.exit(tokenizer.tokenize_state.token_1.clone());
+        tokenizer.tokenize_state.token_1 = Name::Data;
+        State::Retry(StateName::HeadingAttributesAttributeAfter)
+    }
+}
+
+/// After an attribute.
+///
+/// ```markdown
+/// > | ## a {#b .c}
+///             ^  ^
+/// ```
+pub fn attribute_after(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        Some(b'\t' | b' ') => {
+            tokenizer.attempt(State::Next(StateName::HeadingAttributesBetween), State::Nok);
+            State::Retry(space_or_tab(tokenizer))
+        }
+        Some(b'}') => State::Retry(StateName::HeadingAttributesClose),
+        _ => State::Nok,
+    }
+}
+
+/// After whitespace, after an attribute.
+///
+/// ```markdown
+/// > | ## a {#b .c}
+///              ^
+/// ```
+pub fn between(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b'}') {
+        State::Retry(StateName::HeadingAttributesClose)
+    } else {
+        State::Retry(StateName::HeadingAttributesAttributeBefore)
+    }
+}
+
+/// At `}`.
+///
+/// ```markdown
+/// > | ## a {#b .c}
+///                ^
+/// ```
+pub fn close(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.enter(Name::HeadingAttributesMarker);
+    tokenizer.consume();
+    tokenizer.exit(Name::HeadingAttributesMarker);
+    tokenizer.exit(Name::HeadingAttributes);
+    State::Next(StateName::HeadingAttributesAfter)
+}
+
+/// After heading attributes, at optional whitespace.
+///
+/// ```markdown
+/// > | ## a {#b .c}
+///                 ^
+/// ```
+pub fn after(tokenizer: &mut Tokenizer) -> State {
+    if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+        tokenizer.attempt(State::Next(StateName::HeadingAttributesEnd), State::Nok);
+        State::Retry(space_or_tab(tokenizer))
+    } else {
+        State::Retry(StateName::HeadingAttributesEnd)
+    }
+}
+
+/// At eol, after heading attributes and optional whitespace.
+///
+/// ```markdown
+/// > | ## a {#b .c}
+///                 ^
+/// ```
+pub fn end(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n') => State::Ok,
+        _ => State::Nok,
+    }
+}
+
+/// Whether `byte` can occur in the name of an attribute.
+fn is_name_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b':')
+}
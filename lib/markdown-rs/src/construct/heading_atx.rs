@@ -6,7 +6,7 @@
 //! (<small>see [construct][crate::construct] for character groups</small>):
 //!
 //! ```bnf
-//! heading_atx ::= 1*6'#' [ 1*space_or_tab line [ 1*space_or_tab 1*'#' ] ] *space_or_tab
+//! heading_atx ::= 1*6'#' [ 1*space_or_tab line [ 1*space_or_tab 1*'#' ] ] [ 1*space_or_tab heading_attributes ] *space_or_tab
 //! ```
 //!
 //! As this construct occurs in flow, like all flow constructs, it must be
@@ -25,6 +25,10 @@
 //! However, their limit is that they cannot form `<h3>` through `<h6>`
 //! headings.
 //!
+//! When turned on, a heading (atx) can end in
+//! [heading attributes][heading_attributes] (`## a {#b .c}`), which give it
+//! an identifier and classes.
+//!
 //! > 🏛 **Background**: the word *setext* originates from a small markup
 //! > language by Ian Feldman from 1991.
 //! > See [*§ Setext* on Wikipedia][wiki_setext] for more info.
@@ -46,6 +50,7 @@
 //! ## Tokens
 //!
 //! * [`HeadingAtx`][Name::HeadingAtx]
+//! * [`HeadingAttributes`][Name::HeadingAttributes]
 //! * [`HeadingAtxSequence`][Name::HeadingAtxSequence]
 //! * [`HeadingAtxText`][Name::HeadingAtxText]
 //! * [`SpaceOrTab`][Name::SpaceOrTab]
@@ -57,6 +62,7 @@
 //!
 //! [flow]: crate::construct::flow
 //! [heading_setext]: crate::construct::heading_setext
+//! [heading_attributes]: crate::construct::heading_attributes
 //! [hard_break_escape]: crate::construct::hard_break_escape
 //! [html]: https://html.spec.whatwg.org/multipage/sections.html#the-h1,-h2,-h3,-h4,-h5,-and-h6-elements
 //! [wiki_setext]: https://en.wikipedia.org/wiki/Setext
@@ -162,17 +168,14 @@ pub fn at_break(tokenizer: &mut Tokenizer) -> State {
             tokenizer.enter(Name::HeadingAtxSequence);
             State::Retry(StateName::HeadingAtxSequenceFurther)
         }
-        Some(_) => {
-            tokenizer.enter_link(
-                Name::Data,
-                Link {
-                    previous: None,
-                    next: None,
-                    content: Content::Text,
-                },
+        Some(b'{') => {
+            tokenizer.attempt(
+                State::Next(StateName::HeadingAtxAtBreak),
+                State::Next(StateName::HeadingAtxDataStart),
             );
-            State::Retry(StateName::HeadingAtxData)
+            State::Retry(StateName::HeadingAttributesStart)
         }
+        Some(_) => State::Retry(StateName::HeadingAtxDataStart),
     }
 }
 
@@ -194,6 +197,24 @@ pub fn sequence_further(tokenizer: &mut Tokenizer) -> State {
     }
 }
 
+/// At start of text.
+///
+/// ```markdown
+/// > | ## aa
+///        ^
+/// ```
+pub fn data_start(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.enter_link(
+        Name::Data,
+        Link {
+            previous: None,
+            next: None,
+            content: Content::Text,
+        },
+    );
+    State::Retry(StateName::HeadingAtxData)
+}
+
 /// In text.
 ///
 /// ```markdown
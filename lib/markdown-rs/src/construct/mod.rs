@@ -68,6 +68,7 @@
 //! * [gfm label start footnote][gfm_label_start_footnote]
 //! * [gfm table][gfm_table]
 //! * [gfm task list item check][gfm_task_list_item_check]
+//! * [heading attributes][heading_attributes]
 //! * [mdx esm][mdx_esm]
 //! * [mdx expression (flow)][mdx_expression_flow]
 //! * [mdx expression (text)][mdx_expression_text]
@@ -166,6 +167,7 @@ pub mod gfm_label_start_footnote;
 pub mod gfm_table;
 pub mod gfm_task_list_item_check;
 pub mod hard_break_escape;
+pub mod heading_attributes;
 pub mod heading_atx;
 pub mod heading_setext;
 pub mod html_flow;
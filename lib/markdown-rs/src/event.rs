@@ -1670,6 +1670,81 @@ pub enum Name {
     /// > | b
     /// ```
     HardBreakTrailing,
+    /// Whole heading attributes.
+    ///
+    /// ## Info
+    ///
+    /// * **Context**:
+    ///   [`HeadingAtx`][Name::HeadingAtx]
+    /// * **Content model**:
+    ///   [`HeadingAttributesClass`][Name::HeadingAttributesClass],
+    ///   [`HeadingAttributesId`][Name::HeadingAttributesId],
+    ///   [`HeadingAttributesMarker`][Name::HeadingAttributesMarker],
+    ///   [`SpaceOrTab`][Name::SpaceOrTab]
+    /// * **Construct**:
+    ///   [`heading_attributes`][crate::construct::heading_attributes]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | # alpha {#bravo .charlie}
+    ///             ^^^^^^^^^^^^^^^^^
+    /// ```
+    HeadingAttributes,
+    /// Heading attributes class.
+    ///
+    /// ## Info
+    ///
+    /// * **Context**:
+    ///   [`HeadingAttributes`][Name::HeadingAttributes]
+    /// * **Content model**:
+    ///   void
+    /// * **Construct**:
+    ///   [`heading_attributes`][crate::construct::heading_attributes]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | # alpha {#bravo .charlie}
+    ///                      ^^^^^^^
+    /// ```
+    HeadingAttributesClass,
+    /// Heading attributes identifier.
+    ///
+    /// ## Info
+    ///
+    /// * **Context**:
+    ///   [`HeadingAttributes`][Name::HeadingAttributes]
+    /// * **Content model**:
+    ///   void
+    /// * **Construct**:
+    ///   [`heading_attributes`][crate::construct::heading_attributes]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | # alpha {#bravo .charlie}
+    ///               ^^^^^
+    /// ```
+    HeadingAttributesId,
+    /// Heading attributes marker.
+    ///
+    /// ## Info
+    ///
+    /// * **Context**:
+    ///   [`HeadingAttributes`][Name::HeadingAttributes]
+    /// * **Content model**:
+    ///   void
+    /// * **Construct**:
+    ///   [`heading_attributes`][crate::construct::heading_attributes]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | # alpha {#bravo .charlie}
+    ///             ^^          ^   ^
+    /// ```
+    HeadingAttributesMarker,
     /// Whole heading (atx).
     ///
     /// ## Info
@@ -1677,6 +1752,7 @@ pub enum Name {
     /// * **Context**:
     ///   [flow content][crate::construct::flow]
     /// * **Content model**:
+    ///   [`HeadingAttributes`][Name::HeadingAttributes],
     ///   [`HeadingAtxSequence`][Name::HeadingAtxSequence],
     ///   [`HeadingAtxText`][Name::HeadingAtxText],
     ///   [`SpaceOrTab`][Name::SpaceOrTab]
@@ -3381,7 +3457,7 @@ pub enum Name {
 }
 
 /// List of void events, used to make sure everything is working well.
-pub const VOID_EVENTS: [Name; 76] = [
+pub const VOID_EVENTS: [Name; 79] = [
     Name::AttentionSequence,
     Name::AutolinkEmail,
     Name::AutolinkMarker,
@@ -3423,6 +3499,9 @@ pub const VOID_EVENTS: [Name; 76] = [
     Name::FrontmatterSequence,
     Name::HardBreakEscape,
     Name::HardBreakTrailing,
+    Name::HeadingAttributesClass,
+    Name::HeadingAttributesId,
+    Name::HeadingAttributesMarker,
     Name::HeadingAtxSequence,
     Name::HeadingSetextUnderlineSequence,
     Name::HtmlFlowData,
@@ -70,7 +70,10 @@ pub use util::mdx::{
     ExpressionParse as MdxExpressionParse, Signal as MdxSignal,
 };
 
-pub use configuration::{CompileOptions, Constructs, Options, ParseOptions};
+pub use configuration::{
+    CompileOptions, Constructs, FrankenUiClasses, FrankenUiOptions, Options, ParseOptions,
+};
+pub use to_html_frankenui::{FrankenUiDocument, FrankenUiHandler, OutlineHeading};
 
 use alloc::string::String;
 
@@ -164,15 +167,95 @@ pub fn to_mdast(value: &str, options: &ParseOptions) -> Result<mdast::Node, mess
     Ok(node)
 }
 
-/// Turn markdown into FrankenUi HTML , with configuration.
+/// Turn markdown into `FrankenUI` HTML, with configuration.
+///
+/// ## Errors
+///
+/// `to_html_frankenui_with_options()` errors in the same cases as
+/// [`to_html_with_options()`][]: only MDX has syntax errors.
 pub fn to_html_frankenui_with_options(
     value: &str,
     options: &Options,
 ) -> Result<String, message::Message> {
     let (events, parse_state) = parser::parse(value, &options.parse)?;
-    Ok(to_html_frankenui::compile(
-        &events,
-        parse_state.bytes,
-        &options.compile,
-    ))
+    let (html, _) = to_html_frankenui::compile(&events, parse_state.bytes, &options.compile, &());
+    Ok(html)
+}
+
+/// Turn markdown into `FrankenUI` HTML, the outline of its headings, and its
+/// syntax tree, with configuration.
+///
+/// Markdown is parsed once, and the ids in the outline are the ids written
+/// into the HTML.
+///
+/// ## Errors
+///
+/// `to_frankenui_document()` errors in the same cases as
+/// [`to_html_with_options()`][] and [`to_mdast()`][]: only MDX has syntax
+/// errors.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{to_frankenui_document, Options};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// let document = to_frankenui_document("# Hi *Earth*!", &Options::default())?;
+///
+/// assert_eq!(document.outline[0].text, "Hi Earth!");
+/// assert_eq!(document.outline[0].id, "hi-earth");
+/// assert!(document.html.contains("id=\"hi-earth\""));
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_frankenui_document(
+    value: &str,
+    options: &Options,
+) -> Result<FrankenUiDocument, message::Message> {
+    to_frankenui_document_with_handler(value, options, &())
+}
+
+/// Turn markdown into a [`FrankenUiDocument`][], with configuration and a
+/// handler to hook into how HTML is compiled.
+///
+/// ## Errors
+///
+/// `to_frankenui_document_with_handler()` errors in the same cases as
+/// [`to_frankenui_document()`][].
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{to_frankenui_document_with_handler, FrankenUiHandler, Options};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// struct Assets;
+///
+/// impl FrankenUiHandler for Assets {
+///     fn resolve_url(&self, url: &str) -> Option<String> {
+///         url.strip_prefix("./").map(|path| format!("/assets/{}", path))
+///     }
+/// }
+///
+/// let document =
+///     to_frankenui_document_with_handler("![a](./a.png)", &Options::default(), &Assets)?;
+///
+/// assert!(document.html.contains("src=\"/assets/a.png\""));
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_frankenui_document_with_handler(
+    value: &str,
+    options: &Options,
+    handler: &dyn FrankenUiHandler,
+) -> Result<FrankenUiDocument, message::Message> {
+    let (events, parse_state) = parser::parse(value, &options.parse)?;
+    let (html, outline) =
+        to_html_frankenui::compile(&events, parse_state.bytes, &options.compile, handler);
+    let mdast = to_mdast::compile(&events, parse_state.bytes)?;
+    Ok(FrankenUiDocument {
+        html,
+        outline,
+        mdast,
+    })
 }
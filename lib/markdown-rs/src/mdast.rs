@@ -632,6 +632,18 @@ pub struct Heading {
     // Extra.
     /// Rank (between `1` and `6`, both including).
     pub depth: u8,
+    /// Identifier from heading attributes (`{#a}`).
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub id: Option<String>,
+    /// Classes from heading attributes (`{.a .b}`).
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub classes: Vec<String>,
 }
 
 /// Thematic break.
@@ -2069,12 +2081,14 @@ mod tests {
         let mut node = Node::Heading(Heading {
             position: None,
             depth: 1,
+            id: None,
+            classes: vec![],
             children: vec![],
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "Heading { children: [], position: None, depth: 1 }",
+            "Heading { children: [], position: None, depth: 1, id: None, classes: [] }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "", "should support `ToString`");
@@ -2089,7 +2103,7 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "Heading { children: [], position: Some(1:1-1:2 (0-1)), depth: 1 }",
+            "Heading { children: [], position: Some(1:1-1:2 (0-1)), depth: 1, id: None, classes: [] }",
             "should support `position_set`"
         );
     }
@@ -220,11 +220,23 @@ pub enum Name {
     HardBreakEscapeStart,
     HardBreakEscapeAfter,
 
+    HeadingAttributesStart,
+    HeadingAttributesOpenAfter,
+    HeadingAttributesAttributeBefore,
+    HeadingAttributesNameStart,
+    HeadingAttributesName,
+    HeadingAttributesAttributeAfter,
+    HeadingAttributesBetween,
+    HeadingAttributesClose,
+    HeadingAttributesAfter,
+    HeadingAttributesEnd,
+
     HeadingAtxStart,
     HeadingAtxBefore,
     HeadingAtxSequenceOpen,
     HeadingAtxAtBreak,
     HeadingAtxSequenceFurther,
+    HeadingAtxDataStart,
     HeadingAtxData,
 
     HeadingSetextStart,
@@ -678,11 +690,23 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
         Name::HardBreakEscapeStart => construct::hard_break_escape::start,
         Name::HardBreakEscapeAfter => construct::hard_break_escape::after,
 
+        Name::HeadingAttributesStart => construct::heading_attributes::start,
+        Name::HeadingAttributesOpenAfter => construct::heading_attributes::open_after,
+        Name::HeadingAttributesAttributeBefore => construct::heading_attributes::attribute_before,
+        Name::HeadingAttributesNameStart => construct::heading_attributes::name_start,
+        Name::HeadingAttributesName => construct::heading_attributes::name,
+        Name::HeadingAttributesAttributeAfter => construct::heading_attributes::attribute_after,
+        Name::HeadingAttributesBetween => construct::heading_attributes::between,
+        Name::HeadingAttributesClose => construct::heading_attributes::close,
+        Name::HeadingAttributesAfter => construct::heading_attributes::after,
+        Name::HeadingAttributesEnd => construct::heading_attributes::end,
+
         Name::HeadingAtxStart => construct::heading_atx::start,
         Name::HeadingAtxBefore => construct::heading_atx::before,
         Name::HeadingAtxSequenceOpen => construct::heading_atx::sequence_open,
         Name::HeadingAtxAtBreak => construct::heading_atx::at_break,
         Name::HeadingAtxSequenceFurther => construct::heading_atx::sequence_further,
+        Name::HeadingAtxDataStart => construct::heading_atx::data_start,
         Name::HeadingAtxData => construct::heading_atx::data,
 
         Name::HeadingSetextStart => construct::heading_setext::start,
@@ -1,6 +1,6 @@
 //! Turn events into a string of HTML.
 use crate::event::{Event, Kind, Name};
-use crate::mdast::AlignKind;
+use crate::mdast::{AlignKind, Node};
 use crate::util::{
     character_reference::decode as decode_character_reference,
     constant::{SAFE_PROTOCOL_HREF, SAFE_PROTOCOL_SRC},
@@ -12,13 +12,15 @@ use crate::util::{
     skip,
     slice::{Position, Slice},
 };
-use crate::{CompileOptions, LineEnding};
+use crate::{CompileOptions, FrankenUiClasses, LineEnding};
 use alloc::{
+    collections::BTreeMap,
     format,
     string::{String, ToString},
     vec,
     vec::Vec,
 };
+use core::ops::RangeInclusive;
 use core::str;
 use slug::slugify;
 
@@ -57,6 +59,144 @@ struct Media {
     title: Option<String>,
 }
 
+/// Result of [`to_frankenui_document()`][crate::to_frankenui_document].
+#[derive(Clone, Debug, PartialEq)]
+pub struct FrankenUiDocument {
+    /// Compiled HTML.
+    pub html: String,
+    /// Headings, in document order.
+    pub outline: Vec<OutlineHeading>,
+    /// Syntax tree.
+    pub mdast: Node,
+}
+
+/// Hooks into how markdown is compiled to `FrankenUI` HTML.
+///
+/// Every method has a default, so implementations only override what they
+/// need.
+pub trait FrankenUiHandler {
+    /// Rewrite the destination of a link or image, such as to point it at
+    /// where a file ends up in the output.
+    ///
+    /// Gets the destination as written, with character references decoded.
+    /// Return `None` to use the default, which turns relative links to `.md`
+    /// files into links to `.html` files.
+    fn resolve_url(&self, _url: &str) -> Option<String> {
+        None
+    }
+
+    /// Highlight the code of a fenced code block with a language, such as
+    /// `rust` in ` ```rust `.
+    ///
+    /// Gets the code as written, without the fences.
+    /// Return the HTML to place inside `<code>`, which must show the same
+    /// text and keep its line endings, or `None` to leave the code as plain
+    /// text.
+    /// Every line must close the elements it opens, as lines are wrapped one
+    /// by one when the meta highlights lines or asks for line numbers.
+    fn highlight_code(&self, _language: &str, _code: &str) -> Option<String> {
+        None
+    }
+}
+
+/// Handler that keeps every default.
+impl FrankenUiHandler for () {}
+
+/// Heading in the outline of a document.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct OutlineHeading {
+    /// Plain text content of the heading.
+    pub text: String,
+    /// Rank (between `1` and `6`, both including).
+    pub depth: u8,
+    /// The `id` attribute written on the heading element.
+    pub id: String,
+}
+
+/// What the meta of code (fenced) asks for, such as
+/// `title="src/main.rs" {3,5-7} showLineNumbers` in
+/// ` ```rust title="src/main.rs" {3,5-7} showLineNumbers `.
+///
+/// Unknown words are ignored.
+#[derive(Debug, Default)]
+struct FenceMeta {
+    /// File name shown in a header above the code (`title="x"`).
+    title: Option<String>,
+    /// Lines to highlight, 1-indexed (`{3,5-7}`).
+    highlight: Vec<RangeInclusive<usize>>,
+    /// Whether to show a gutter with line numbers (`showLineNumbers`).
+    line_numbers: bool,
+    /// Whether to leave out the copy button (`nocopy`).
+    no_copy: bool,
+}
+
+impl FenceMeta {
+    /// Parse the meta of code (fenced), as written.
+    fn parse(value: &str) -> FenceMeta {
+        let mut meta = FenceMeta::default();
+        let mut rest = value.trim_start();
+
+        while !rest.is_empty() {
+            let word_end = if rest.starts_with('{') {
+                rest.find('}').map_or(rest.len(), |index| index + 1)
+            } else if let Some((key, value)) = rest
+                .split_once('=')
+                .filter(|(key, _)| !key.contains(char::is_whitespace))
+                .filter(|(_, value)| value.starts_with(['"', '\'']))
+            {
+                // Quoted values, which can contain whitespace
+                let quote = &value[..1];
+                let (text, end) = match value[1..].find(quote) {
+                    Some(index) => (&value[1..=index], index + 2),
+                    None => (&value[1..], value.len()),
+                };
+                if key == "title" {
+                    meta.title = Some(text.into());
+                }
+                key.len() + 1 + end
+            } else {
+                rest.find(char::is_whitespace).unwrap_or(rest.len())
+            };
+
+            let word = &rest[..word_end];
+            if let Some(ranges) = word.strip_prefix('{') {
+                meta.highlight.extend(
+                    ranges
+                        .trim_end_matches('}')
+                        .split(',')
+                        .filter_map(parse_line_range),
+                );
+            } else if word == "showLineNumbers" {
+                meta.line_numbers = true;
+            } else if word == "nocopy" {
+                meta.no_copy = true;
+            }
+
+            rest = rest[word_end..].trim_start();
+        }
+
+        meta
+    }
+
+    /// Whether the code is wrapped per line.
+    fn wraps_lines(&self) -> bool {
+        !self.highlight.is_empty() || self.line_numbers
+    }
+
+    /// Whether the line (1-indexed) is highlighted.
+    fn highlights(&self, line: usize) -> bool {
+        self.highlight.iter().any(|range| range.contains(&line))
+    }
+}
+
+/// Parse a line (`3`) or range of lines (`5-7`).
+fn parse_line_range(value: &str) -> Option<RangeInclusive<usize>> {
+    let (start, end) = value.split_once('-').unwrap_or((value, value));
+    let start = start.trim().parse().ok()?;
+    let end = end.trim().parse().ok()?;
+    Some(start..=end)
+}
+
 /// Representation of a definition.
 #[derive(Debug)]
 struct Definition {
@@ -74,7 +214,6 @@ struct Definition {
 
 /// Context used to compile markdown.
 #[allow(clippy::struct_excessive_bools)]
-#[derive(Debug)]
 struct CompileContext<'a> {
     // Static info.
     /// List of events.
@@ -83,16 +222,33 @@ struct CompileContext<'a> {
     bytes: &'a [u8],
     /// Configuration.
     options: &'a CompileOptions,
+    /// Hooks.
+    handler: &'a dyn FrankenUiHandler,
     // Fields used by handlers to track the things they need to track to
     // compile markdown.
     /// Rank of heading (atx).
     heading_atx_rank: Option<usize>,
+    /// Buffer of heading (atx) text.
+    heading_atx_buffer: Option<String>,
+    /// Identifier from heading attributes.
+    heading_attributes_id: Option<String>,
+    /// Classes from heading attributes.
+    heading_attributes_classes: Vec<String>,
     /// Buffer of heading (setext) text.
     heading_setext_buffer: Option<String>,
     /// Whether raw (flow) (code (fenced), math (flow)) or code (indented) contains data.
     raw_flow_seen_data: Option<bool>,
     /// Number of raw (flow) fences.
     raw_flow_fences_count: Option<usize>,
+    /// Language of code (fenced), from its info.
+    raw_flow_language: Option<String>,
+    /// Meta of code (fenced).
+    raw_flow_meta: Option<FenceMeta>,
+    /// Text of code (fenced) with a language or per line wrappers, for the
+    /// handler to highlight.
+    raw_flow_code: Option<String>,
+    /// Number of elements around raw (flow), closed after it.
+    raw_flow_wrappers: usize,
     /// Whether we are in code (text).
     raw_text_inside: bool,
     /// Whether we are in image text.
@@ -111,6 +267,10 @@ struct CompileContext<'a> {
     gfm_footnote_definitions: Vec<(String, String)>,
     gfm_footnote_definition_calls: Vec<(String, usize)>,
     gfm_footnote_definition_stack: Vec<(usize, usize)>,
+    /// Headings seen so far.
+    outline: Vec<OutlineHeading>,
+    /// Ids given to headings so far, with how often each was taken again.
+    heading_ids: BTreeMap<String, usize>,
     /// Whether we are in a GFM table head.
     gfm_table_in_head: bool,
     /// Current GFM table alignment.
@@ -138,15 +298,23 @@ impl<'a> CompileContext<'a> {
         events: &'a [Event],
         bytes: &'a [u8],
         options: &'a CompileOptions,
+        handler: &'a dyn FrankenUiHandler,
         line_ending: LineEnding,
     ) -> CompileContext<'a> {
         CompileContext {
             events,
             bytes,
             heading_atx_rank: None,
+            heading_atx_buffer: None,
+            heading_attributes_id: None,
+            heading_attributes_classes: vec![],
             heading_setext_buffer: None,
             raw_flow_seen_data: None,
             raw_flow_fences_count: None,
+            raw_flow_language: None,
+            raw_flow_meta: None,
+            raw_flow_code: None,
+            raw_flow_wrappers: 0,
             raw_text_inside: false,
             character_reference_marker: None,
             list_expect_first_marker: None,
@@ -155,6 +323,8 @@ impl<'a> CompileContext<'a> {
             gfm_footnote_definitions: vec![],
             gfm_footnote_definition_calls: vec![],
             gfm_footnote_definition_stack: vec![],
+            outline: vec![],
+            heading_ids: BTreeMap::new(),
             gfm_table_in_head: false,
             gfm_table_align: None,
             gfm_table_column: 0,
@@ -166,6 +336,7 @@ impl<'a> CompileContext<'a> {
             buffers: vec![String::new()],
             index: 0,
             options,
+            handler,
         }
     }
 
@@ -204,8 +375,14 @@ impl<'a> CompileContext<'a> {
     }
 }
 
-/// Turn events and bytes into a string of HTML.
-pub fn compile(events: &[Event], bytes: &[u8], options: &CompileOptions) -> String {
+/// Turn events and bytes into a string of HTML and the outline of its
+/// headings.
+pub fn compile(
+    events: &[Event],
+    bytes: &[u8],
+    options: &CompileOptions,
+    handler: &dyn FrankenUiHandler,
+) -> (String, Vec<OutlineHeading>) {
     let mut index = 0;
     let mut line_ending_inferred = None;
 
@@ -229,7 +406,7 @@ pub fn compile(events: &[Event], bytes: &[u8], options: &CompileOptions) -> Stri
     let line_ending_default =
         line_ending_inferred.unwrap_or_else(|| options.default_line_ending.clone());
 
-    let mut context = CompileContext::new(events, bytes, options, line_ending_default);
+    let mut context = CompileContext::new(events, bytes, options, handler, line_ending_default);
     let mut definition_indices = vec![];
     let mut index = 0;
     let mut definition_inside = false;
@@ -292,11 +469,8 @@ pub fn compile(events: &[Event], bytes: &[u8], options: &CompileOptions) -> Stri
     }
 
     debug_assert_eq!(context.buffers.len(), 1, "expected 1 final buffer");
-    context
-        .buffers
-        .first()
-        .expect("expected 1 final buffer")
-        .into()
+    let html = context.buffers.pop().expect("expected 1 final buffer");
+    (html, context.outline)
 }
 
 /// Handle the event at `index`.
@@ -364,8 +538,7 @@ fn enter(context: &mut CompileContext) {
 /// Handle [`Exit`][Kind::Exit].
 fn exit(context: &mut CompileContext) {
     match context.events[context.index].name {
-        Name::CodeFencedFenceMeta
-        | Name::MathFlowFenceMeta
+        Name::MathFlowFenceMeta
         | Name::MdxJsxTextTag
         | Name::MdxTextExpression
         | Name::Resource => {
@@ -390,6 +563,7 @@ fn exit(context: &mut CompileContext) {
         Name::CodeFenced | Name::CodeIndented | Name::MathFlow => on_exit_raw_flow(context),
         Name::CodeFencedFence | Name::MathFlowFence => on_exit_raw_flow_fence(context),
         Name::CodeFencedFenceInfo => on_exit_raw_flow_fence_info(context),
+        Name::CodeFencedFenceMeta => on_exit_raw_flow_fence_meta(context),
         Name::CodeFlowChunk | Name::MathFlowChunk => on_exit_raw_flow_chunk(context),
         Name::CodeText | Name::MathText => on_exit_raw_text(context),
         Name::Definition => on_exit_definition(context),
@@ -418,6 +592,8 @@ fn exit(context: &mut CompileContext) {
         Name::GfmTaskListItemCheck => on_exit_gfm_task_list_item_check(context),
         Name::GfmTaskListItemValueChecked => on_exit_gfm_task_list_item_value_checked(context),
         Name::HardBreakEscape | Name::HardBreakTrailing => on_exit_break(context),
+        Name::HeadingAttributesClass => on_exit_heading_attributes_class(context),
+        Name::HeadingAttributesId => on_exit_heading_attributes_id(context),
         Name::HeadingAtx => on_exit_heading_atx(context),
         Name::HeadingAtxSequence => on_exit_heading_atx_sequence(context),
         Name::HeadingAtxText => on_exit_heading_atx_text(context),
@@ -453,37 +629,43 @@ fn on_enter_buffer(context: &mut CompileContext) {
 fn on_enter_block_quote(context: &mut CompileContext) {
     context.tight_stack.push(false);
     context.line_ending_if_needed();
-    context.push("<blockquote class=\"uk-blockquote mt-4\">");
+    let options = context.options;
+    context.push("<blockquote");
+    push_class(context, &options.frankenui.classes.block_quote);
+    context.push(">");
 }
 
 /// Handle [`Enter`][Kind::Enter]:[`CodeIndented`][Name::CodeIndented].
 fn on_enter_code_indented(context: &mut CompileContext) {
     context.raw_flow_seen_data = Some(false);
     context.line_ending_if_needed();
-    context.push("<pre class=\"mt-4\"><code>");
+    let options = context.options;
+    context.push("<pre");
+    push_class(context, &options.frankenui.classes.code_block);
+    context.push("><code>");
 }
 
 /// Handle [`Enter`][Kind::Enter]:{[`CodeFenced`][Name::CodeFenced],[`MathFlow`][Name::MathFlow]}.
 fn on_enter_raw_flow(context: &mut CompileContext) {
     context.raw_flow_seen_data = Some(false);
     context.line_ending_if_needed();
-    // Note that no `>` is used, which is added later (due to info)
-    context.push("<pre class=\"mt-4\"><code");
+    // Note that nothing is pushed yet, which is done at the end of the
+    // opening fence (due to info and meta)
     context.raw_flow_fences_count = Some(0);
-
-    if context.events[context.index].name == Name::MathFlow {
-        context.push(" class=\"language-math math-display\"");
-    }
 }
 
 /// Handle [`Enter`][Kind::Enter]:{[`CodeText`][Name::CodeText],[`MathText`][Name::MathText]}.
 fn on_enter_raw_text(context: &mut CompileContext) {
     context.raw_text_inside = true;
     if !context.image_alt_inside {
-        context.push("<code class=\"uk-codespan\"");
-        if context.events[context.index].name == Name::MathText {
-            context.push(" class=\"language-math math-inline\"");
-        }
+        let code_text = &context.options.frankenui.classes.code_text;
+        let classes = if context.events[context.index].name == Name::MathText {
+            join_classes(&[code_text, "language-math math-inline"])
+        } else {
+            code_text.clone()
+        };
+        context.push("<code");
+        push_class(context, &classes);
         context.push(">");
     }
     context.buffer();
@@ -511,7 +693,10 @@ fn on_enter_definition_destination_string(context: &mut CompileContext) {
 /// Handle [`Enter`][Kind::Enter]:[`Emphasis`][Name::Emphasis].
 fn on_enter_emphasis(context: &mut CompileContext) {
     if !context.image_alt_inside {
-        context.push("<em class=\"italic\">");
+        let options = context.options;
+        context.push("<em");
+        push_class(context, &options.frankenui.classes.emphasis);
+        context.push(">");
     }
 }
 
@@ -540,7 +725,10 @@ fn on_enter_gfm_footnote_call(context: &mut CompileContext) {
 /// Handle [`Enter`][Kind::Enter]:[`GfmStrikethrough`][Name::GfmStrikethrough].
 fn on_enter_gfm_strikethrough(context: &mut CompileContext) {
     if !context.image_alt_inside {
-        context.push("<del class=\"line-through\">");
+        let options = context.options;
+        context.push("<del");
+        push_class(context, &options.frankenui.classes.strikethrough);
+        context.push(">");
     }
 }
 
@@ -549,8 +737,12 @@ fn on_enter_gfm_table(context: &mut CompileContext) {
     let align = gfm_table_align(context.events, context.index);
     context.gfm_table_align = Some(align);
     context.line_ending_if_needed();
-    context
-        .push("<div class=\"uk-overflow-auto mt-4\"><table class=\"uk-table uk-table-divider\">");
+    let options = context.options;
+    context.push("<div");
+    push_class(context, &options.frankenui.classes.table_wrapper);
+    context.push("><table");
+    push_class(context, &options.frankenui.classes.table);
+    context.push(">");
 }
 
 /// Handle [`Enter`][Kind::Enter]:[`GfmTableBody`][Name::GfmTableBody].
@@ -576,10 +768,11 @@ fn on_enter_gfm_table_cell(context: &mut CompileContext) {
             context.push("<td");
         }
 
+        let classes = &context.options.frankenui.classes;
         match value {
-            AlignKind::Left => context.push(" class=\"uk-text-left\""),
-            AlignKind::Right => context.push(" class=\"uk-text-right\""),
-            AlignKind::Center => context.push(" class=\"uk-text-center\""),
+            AlignKind::Left => push_class(context, &classes.table_cell_left),
+            AlignKind::Right => push_class(context, &classes.table_cell_right),
+            AlignKind::Center => push_class(context, &classes.table_cell_center),
             AlignKind::None => {}
         }
 
@@ -603,7 +796,10 @@ fn on_enter_gfm_table_row(context: &mut CompileContext) {
 /// Handle [`Enter`][Kind::Enter]:[`GfmTaskListItemCheck`][Name::GfmTaskListItemCheck].
 fn on_enter_gfm_task_list_item_check(context: &mut CompileContext) {
     if !context.image_alt_inside {
-        context.push("<input class=\"uk-checkbox\" type=\"checkbox\" ");
+        let options = context.options;
+        context.push("<input");
+        push_class(context, &options.frankenui.classes.task_list_item_check);
+        context.push(" type=\"checkbox\" ");
         if !context.options.gfm_task_list_item_checkable {
             context.push("disabled=\"\" ");
         }
@@ -657,11 +853,14 @@ fn on_enter_list(context: &mut CompileContext) {
     context.line_ending_if_needed();
 
     // Note: no `>`.
-    context.push(if context.events[context.index].name == Name::ListOrdered {
-        "<ul class=\"uk-list uk-list-decimal mt-4\""
+    let classes = &context.options.frankenui.classes;
+    if context.events[context.index].name == Name::ListOrdered {
+        context.push("<ol");
+        push_class(context, &classes.list_ordered);
     } else {
-        "<ul class=\"uk-list uk-list-disc mt-4\""
-    });
+        context.push("<ul");
+        push_class(context, &classes.list_unordered);
+    }
     context.list_expect_first_marker = Some(true);
 }
 
@@ -683,7 +882,10 @@ fn on_enter_paragraph(context: &mut CompileContext) {
 
     if !tight {
         context.line_ending_if_needed();
-        context.push("<p class=\"uk-paragraph\">");
+        let options = context.options;
+        context.push("<p");
+        push_class(context, &options.frankenui.classes.paragraph);
+        context.push(">");
     }
 }
 
@@ -704,7 +906,10 @@ fn on_enter_resource_destination_string(context: &mut CompileContext) {
 /// Handle [`Enter`][Kind::Enter]:[`Strong`][Name::Strong].
 fn on_enter_strong(context: &mut CompileContext) {
     if !context.image_alt_inside {
-        context.push("<strong class=\"font-bold\">");
+        let options = context.options;
+        context.push("<strong");
+        push_class(context, &options.frankenui.classes.strong);
+        context.push(">");
     }
 }
 
@@ -793,15 +998,17 @@ fn on_exit_character_reference_value(context: &mut CompileContext) {
 /// Handle [`Exit`][Kind::Exit]:{[`CodeFlowChunk`][Name::CodeFlowChunk],[`MathFlowChunk`][Name::MathFlowChunk]}.
 fn on_exit_raw_flow_chunk(context: &mut CompileContext) {
     context.raw_flow_seen_data = Some(true);
-    context.push(&encode(
-        &Slice::from_position(
-            context.bytes,
-            &Position::from_exit_event(context.events, context.index),
-        )
-        // Must serialize to get virtual spaces.
-        .serialize(),
-        context.encode_html,
-    ));
+    // Must serialize to get virtual spaces.
+    let value = Slice::from_position(
+        context.bytes,
+        &Position::from_exit_event(context.events, context.index),
+    )
+    .serialize();
+    context.push(&encode(&value, context.encode_html));
+
+    if let Some(code) = context.raw_flow_code.as_mut() {
+        code.push_str(&value);
+    }
 }
 
 /// Handle [`Exit`][Kind::Exit]:{[`CodeFencedFence`][Name::CodeFencedFence],[`MathFlowFence`][Name::MathFlowFence]}.
@@ -811,8 +1018,89 @@ fn on_exit_raw_flow_fence(context: &mut CompileContext) {
         .expect("expected `raw_flow_fences_count`");
 
     if count == 0 {
+        let meta = context.raw_flow_meta.take().unwrap_or_default();
+        let wraps_lines = meta.wraps_lines();
+        let options = &context.options.frankenui;
+        let classes = &options.classes;
+        let code = context.events[context.index].name == Name::CodeFencedFence;
+        let badge = context
+            .raw_flow_language
+            .clone()
+            .filter(|_| code && options.code_language_badge);
+        let copy = code && options.code_copy_button && !meta.no_copy;
+        // The outermost element gets the classes of the code block
+        let mut outer = true;
+
+        if let Some(title) = &meta.title {
+            context.push("<div");
+            push_class(
+                context,
+                &join_classes(&[&classes.code_block_card, &classes.code_block]),
+            );
+            context.push("><div");
+            push_class(context, &classes.code_block_title);
+            context.push(">");
+            context.push(&encode(title, context.encode_html));
+            context.push("</div>");
+            context.raw_flow_wrappers += 1;
+            outer = false;
+        }
+        // Badge and copy button, over the top right corner of the code
+        if badge.is_some() || copy {
+            context.push("<div");
+            push_class(
+                context,
+                &join_classes(&["relative", if outer { &classes.code_block } else { "" }]),
+            );
+            context.push("><div");
+            push_class(context, &classes.code_block_toolbar);
+            context.push(">");
+            if let Some(language) = badge {
+                context.push("<span");
+                push_class(context, &classes.code_block_badge);
+                context.push(">");
+                context.push(&language);
+                context.push("</span>");
+            }
+            if copy {
+                context.push("<button type=\"button\"");
+                push_class(context, &classes.code_block_copy_button);
+                context.push(" data-copy-code=\"\" aria-label=\"Copy code\"><uk-icon icon=\"copy\"></uk-icon></button>");
+            }
+            context.push("</div>");
+            context.raw_flow_wrappers += 1;
+            outer = false;
+        }
+        context.push("<pre");
+        push_class(
+            context,
+            if outer {
+                &classes.code_block
+            } else {
+                &classes.code_block_inner
+            },
+        );
+        context.push("><code");
+        if context.events[context.index].name == Name::MathFlowFence {
+            context.push(" class=\"language-math math-display\"");
+        } else if let Some(language) = context.raw_flow_language.take() {
+            context.push(" class=\"language-");
+            context.push(&language);
+            context.push("\"");
+            context.raw_flow_language = Some(language);
+        }
+        if wraps_lines {
+            context.push(" data-lines=\"\"");
+        }
         context.push(">");
         context.slurp_one_line_ending = true;
+
+        // Collect the code for the handler, next to the default HTML
+        if context.raw_flow_language.is_some() || wraps_lines {
+            context.raw_flow_code = Some(String::new());
+            context.buffer();
+        }
+        context.raw_flow_meta = Some(meta);
     }
 
     context.raw_flow_fences_count = Some(count + 1);
@@ -823,9 +1111,19 @@ fn on_exit_raw_flow_fence(context: &mut CompileContext) {
 /// Note: math (flow) does not support `info`.
 fn on_exit_raw_flow_fence_info(context: &mut CompileContext) {
     let value = context.resume();
-    context.push(" class=\"language-");
-    context.push(&value);
-    context.push("\"");
+    context.raw_flow_language = Some(value);
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`CodeFencedFenceMeta`][Name::CodeFencedFenceMeta].
+fn on_exit_raw_flow_fence_meta(context: &mut CompileContext) {
+    context.resume();
+    // Parsed as written, as quotes would be encoded
+    let value = Slice::from_position(
+        context.bytes,
+        &Position::from_exit_event(context.events, context.index),
+    )
+    .serialize();
+    context.raw_flow_meta = Some(FenceMeta::parse(&value));
 }
 
 /// Handle [`Exit`][Kind::Exit]:{[`CodeFenced`][Name::CodeFenced],[`CodeIndented`][Name::CodeIndented],[`MathFlow`][Name::MathFlow]}.
@@ -844,9 +1142,31 @@ fn on_exit_raw_flow(context: &mut CompileContext) {
             && !matches!(context.events[context.index - 1].name, Name::CodeFencedFence | Name::MathFlowFence)
         {
             context.line_ending();
+            if let Some(code) = context.raw_flow_code.as_mut() {
+                code.push_str(context.line_ending_default.as_str());
+            }
         }
     }
 
+    if let Some(code) = context.raw_flow_code.take() {
+        let default = context.resume();
+        let html = context
+            .raw_flow_language
+            .take()
+            .and_then(|language| context.handler.highlight_code(&language, &code))
+            .unwrap_or(default);
+
+        match context.raw_flow_meta.as_ref() {
+            Some(meta) if meta.wraps_lines() => {
+                let html = wrap_lines(&html, meta, &context.options.frankenui.classes);
+                context.push(&html);
+            }
+            _ => context.push(&html),
+        }
+    }
+    context.raw_flow_language = None;
+    context.raw_flow_meta = None;
+
     // But in most cases, it’s simpler: when we’ve seen some data, emit an extra
     // line ending when needed.
     if context
@@ -858,6 +1178,10 @@ fn on_exit_raw_flow(context: &mut CompileContext) {
     }
 
     context.push("</code></pre>");
+    for _ in 0..context.raw_flow_wrappers {
+        context.push("</div>");
+    }
+    context.raw_flow_wrappers = 0;
 
     if let Some(count) = context.raw_flow_fences_count.take() {
         if count < 2 {
@@ -868,6 +1192,63 @@ fn on_exit_raw_flow(context: &mut CompileContext) {
     context.slurp_one_line_ending = false;
 }
 
+/// Wrap every line of the HTML of code in a `code-line` span, marking
+/// highlighted lines and adding line numbers as the meta asks.
+///
+/// Line endings stay between the wrappers.
+fn wrap_lines(html: &str, meta: &FenceMeta, classes: &FrankenUiClasses) -> String {
+    let mut result = String::with_capacity(html.len() * 2);
+
+    for (index, line) in html.split_inclusive('\n').enumerate() {
+        let number = index + 1;
+        let content = line.trim_end_matches(['\n', '\r']);
+
+        result.push_str("<span");
+        result.push_str(&class_attribute(if meta.highlights(number) {
+            &classes.code_line_highlighted
+        } else {
+            &classes.code_line
+        }));
+        result.push('>');
+        if meta.line_numbers {
+            result.push_str("<span");
+            result.push_str(&class_attribute(&classes.code_line_number));
+            result.push_str(" aria-hidden=\"true\">");
+            result.push_str(&number.to_string());
+            result.push_str("</span>");
+        }
+        result.push_str(content);
+        result.push_str("</span>");
+        result.push_str(&line[content.len()..]);
+    }
+
+    result
+}
+
+/// Push a `class` attribute, unless there are no classes.
+fn push_class(context: &mut CompileContext, classes: &str) {
+    context.push(&class_attribute(classes));
+}
+
+/// Turn classes into a `class` attribute, or nothing when there are none.
+fn class_attribute(classes: &str) -> String {
+    if classes.is_empty() {
+        String::new()
+    } else {
+        format!(" class=\"{}\"", encode(classes, true))
+    }
+}
+
+/// Join the classes of several lists, skipping empty ones.
+fn join_classes(lists: &[&str]) -> String {
+    lists
+        .iter()
+        .filter(|classes| !classes.is_empty())
+        .copied()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 /// Handle [`Exit`][Kind::Exit]:{[`CodeText`][Name::CodeText],[`MathText`][Name::MathText]}.
 fn on_exit_raw_text(context: &mut CompileContext) {
     let result = context.resume();
@@ -1228,19 +1609,57 @@ fn on_exit_gfm_task_list_item_value_checked(context: &mut CompileContext) {
     }
 }
 
+/// Handle [`Exit`][Kind::Exit]:[`HeadingAttributesClass`][Name::HeadingAttributesClass].
+fn on_exit_heading_attributes_class(context: &mut CompileContext) {
+    let value = Slice::from_position(
+        context.bytes,
+        &Position::from_exit_event(context.events, context.index),
+    )
+    .serialize();
+    context.heading_attributes_classes.push(value);
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`HeadingAttributesId`][Name::HeadingAttributesId].
+fn on_exit_heading_attributes_id(context: &mut CompileContext) {
+    let value = Slice::from_position(
+        context.bytes,
+        &Position::from_exit_event(context.events, context.index),
+    )
+    .serialize();
+    context.heading_attributes_id = Some(value);
+}
+
 /// Handle [`Exit`][Kind::Exit]:[`HeadingAtx`][Name::HeadingAtx].
+///
+/// The heading is written here, as its id and classes are only known once
+/// the attributes after its text are seen.
 fn on_exit_heading_atx(context: &mut CompileContext) {
     let rank = context
         .heading_atx_rank
         .take()
         .expect("`heading_atx_rank` must be set in headings");
+    let text = context.heading_atx_buffer.take();
+    let explicit_id = context.heading_attributes_id.take();
+    let classes = core::mem::take(&mut context.heading_attributes_classes);
+
+    // Headings without text or an explicit id have no id and stay out of
+    // the outline.
+    let id = if text.is_some() || explicit_id.is_some() {
+        Some(add_outline_heading(
+            context,
+            text.as_deref().unwrap_or_default(),
+            rank,
+            explicit_id,
+        ))
+    } else {
+        None
+    };
 
-    context.push("</h1>");
-
-    if rank == 1 || rank == 2 {
-        context.line_ending_if_needed();
-        context.push("<hr class=\"uk-hr mb-6\"/>");
+    on_enter_heading(context, rank, id.as_deref(), &classes);
+    if let Some(text) = text {
+        context.push(&text);
     }
+    on_exit_heading(context, rank);
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`HeadingAtxSequence`][Name::HeadingAtxSequence].
@@ -1254,31 +1673,13 @@ fn on_exit_heading_atx_sequence(context: &mut CompileContext) {
         .len();
         context.line_ending_if_needed();
         context.heading_atx_rank = Some(rank);
-        context.push("<h1 class=\"uk-h");
-        context.push(&rank.to_string());
-        context.push(" mt-8 mb-4\"");
-        // We'll add the id after we get the text content
-        context.push(">");
     }
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`HeadingAtxText`][Name::HeadingAtxText].
 fn on_exit_heading_atx_text(context: &mut CompileContext) {
-    let value = context.resume();
-
-    // Generate ID from the heading text using slugify
-    let id = slugify(&value);
-
-    // Insert the id attribute before the closing quote of the opening tag
-    // We need to go back and modify the opening tag
-    let last_buffer = context.buffers.last_mut().unwrap();
-    if let Some(pos) = last_buffer.rfind(">") {
-        let before_id = &last_buffer[..pos];
-        let after_id = &last_buffer[pos..];
-        *last_buffer = format!("{} id=\"{}\"{}", before_id, id, after_id);
-    }
-
-    context.push(&value);
+    let buf = context.resume();
+    context.heading_atx_buffer = Some(buf);
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`HeadingSetextText`][Name::HeadingSetextText].
@@ -1296,24 +1697,126 @@ fn on_exit_heading_setext_underline_sequence(context: &mut CompileContext) {
         .expect("`heading_atx_rank` must be set in headings");
     let position = Position::from_exit_event(context.events, context.index);
     let head = context.bytes[position.start.index];
-    let rank = if head == b'-' { "2" } else { "1" };
-
-    // Generate ID from the heading text using slugify
-    let id = slugify(&text);
+    let rank = if head == b'-' { 2 } else { 1 };
+    let id = add_outline_heading(context, &text, rank, None);
 
     context.line_ending_if_needed();
-    context.push("<h1 class=\"uk-h");
-    context.push(rank);
-    context.push(" mt-8 mb-4\" id=\"");
-    context.push(&id);
-    context.push("\">");
+    on_enter_heading(context, rank, Some(&id), &[]);
     context.push(&text);
-    context.push("</h1>");
+    on_exit_heading(context, rank);
+}
+
+/// Open a heading of `rank`, with `id` when known, and `extra` classes after
+/// those of the class map.
+fn on_enter_heading(context: &mut CompileContext, rank: usize, id: Option<&str>, extra: &[String]) {
+    let classes = &context.options.frankenui.classes;
+    let heading = match rank {
+        1 => &classes.heading_1,
+        2 => &classes.heading_2,
+        3 => &classes.heading_3,
+        4 => &classes.heading_4,
+        5 => &classes.heading_5,
+        _ => &classes.heading_6,
+    };
+    let mut classes: Vec<&str> = vec![heading];
+    classes.extend(extra.iter().map(String::as_str));
+    let classes = join_classes(&classes);
+    context.push("<h");
+    context.push(&rank.to_string());
+    push_class(context, &classes);
+    if let Some(id) = id.filter(|id| !id.is_empty()) {
+        context.push(" id=\"");
+        context.push(id);
+        context.push("\"");
+    }
+    context.push(">");
+}
 
-    if rank == "1" || rank == "2" {
+/// Close a heading of `rank`, followed by a rule for ranks 1 and 2 when
+/// turned on.
+fn on_exit_heading(context: &mut CompileContext, rank: usize) {
+    context.push("</h");
+    context.push(&rank.to_string());
+    context.push(">");
+
+    let options = context.options;
+    if options.frankenui.heading_rule && (rank == 1 || rank == 2) {
         context.line_ending_if_needed();
-        context.push("<hr class=\"uk-hr mb-6\"/>");
+        context.push("<hr");
+        push_class(context, &options.frankenui.classes.heading_rule);
+        context.push("/>");
+    }
+}
+
+/// Add a heading to the outline, returning the id to write on it.
+///
+/// The id is the one from heading attributes, or else a slug of the plain
+/// text of the heading, so it does not depend on inline markup.
+/// Like GitHub, ids that are taken get a numeric suffix (`example-1`,
+/// `example-2`), so no two headings share one.
+fn add_outline_heading(
+    context: &mut CompileContext,
+    html: &str,
+    rank: usize,
+    explicit_id: Option<String>,
+) -> String {
+    let text = plain_text(html);
+    let id = unique_heading_id(
+        &mut context.heading_ids,
+        explicit_id.unwrap_or_else(|| slugify(&text)),
+    );
+    #[allow(clippy::cast_possible_truncation)]
+    let depth = rank as u8;
+    context.outline.push(OutlineHeading {
+        text,
+        depth,
+        id: id.clone(),
+    });
+    id
+}
+
+/// Take `slug` as an id, or the first of `slug-1`, `slug-2`, and so on, that
+/// is not taken yet.
+///
+/// Empty slugs are not written, so they are never taken.
+/// Ids from heading attributes go through here too.
+fn unique_heading_id(taken: &mut BTreeMap<String, usize>, slug: String) -> String {
+    if slug.is_empty() {
+        return slug;
+    }
+
+    let mut id = slug.clone();
+    if let Some(mut count) = taken.get(&slug).copied() {
+        loop {
+            count += 1;
+            id = format!("{}-{}", slug, count);
+            if !taken.contains_key(&id) {
+                break;
+            }
+        }
+        taken.insert(slug, count);
     }
+    taken.insert(id.clone(), 0);
+    id
+}
+
+/// Turn compiled phrasing content back into text: drop tags and decode the
+/// characters that [`encode()`][] escapes.
+fn plain_text(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        text.push_str(&rest[..start]);
+        rest = rest[start..]
+            .find('>')
+            .map_or("", |end| &rest[start + end + 1..]);
+    }
+    text.push_str(rest);
+    text.trim()
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&amp;", "&")
 }
 
 /// Handle [`Exit`][Kind::Exit]:{[`HtmlFlow`][Name::HtmlFlow],[`HtmlText`][Name::HtmlText]}.
@@ -1362,14 +1865,16 @@ fn on_exit_line_ending(context: &mut CompileContext) {
     {
         context.slurp_one_line_ending = false;
     } else {
-        context.push(&encode(
-            Slice::from_position(
-                context.bytes,
-                &Position::from_exit_event(context.events, context.index),
-            )
-            .as_str(),
-            context.encode_html,
-        ));
+        let slice = Slice::from_position(
+            context.bytes,
+            &Position::from_exit_event(context.events, context.index),
+        );
+        let value = slice.as_str();
+        context.push(&encode(value, context.encode_html));
+
+        if let Some(code) = context.raw_flow_code.as_mut() {
+            code.push_str(value);
+        }
     }
 }
 
@@ -1377,7 +1882,11 @@ fn on_exit_line_ending(context: &mut CompileContext) {
 fn on_exit_list(context: &mut CompileContext) {
     context.tight_stack.pop();
     context.line_ending();
-    context.push("</ul>");
+    context.push(if context.events[context.index].name == Name::ListOrdered {
+        "</ol>"
+    } else {
+        "</ul>"
+    });
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`ListItem`][Name::ListItem].
@@ -1471,7 +1980,10 @@ fn on_exit_media(context: &mut CompileContext) {
         if media.image {
             context.push("<img src=\"");
         } else {
-            context.push("<a class=\"uk-link\" href=\"");
+            let options = context.options;
+            context.push("<a");
+            push_class(context, &options.frankenui.classes.link);
+            context.push(" href=\"");
         }
 
         let destination = if let Some(index) = definition_index {
@@ -1481,14 +1993,15 @@ fn on_exit_media(context: &mut CompileContext) {
         };
 
         if let Some(destination) = destination {
+            let destination = resolve_url(context, destination);
             let allow_dangerous_protocol = context.options.allow_dangerous_protocol
                 || (context.options.allow_any_img_src && media.image);
 
             let url = if allow_dangerous_protocol {
-                sanitize(destination)
+                sanitize(&destination)
             } else {
                 sanitize_with_protocols(
-                    destination,
+                    &destination,
                     if media.image {
                         &SAFE_PROTOCOL_SRC
                     } else {
@@ -1562,20 +2075,26 @@ fn on_exit_reference_string(context: &mut CompileContext) {
 /// Handle [`Exit`][Kind::Exit]:[`ResourceDestinationString`][Name::ResourceDestinationString].
 fn on_exit_resource_destination_string(context: &mut CompileContext) {
     let buf = context.resume();
+    context.media_stack.last_mut().unwrap().destination = Some(buf);
+    context.encode_html = true;
+}
 
-    // Convert .md extensions to .html only for relative links
-    let processed_buf = if buf.ends_with(".md") && !buf.contains("://") && !buf.starts_with("http")
+/// Resolve a destination with the handler, falling back to converting `.md`
+/// extensions to `.html` for relative links.
+///
+/// Only a lowercase `.md` is converted, other casings are left as written.
+#[allow(clippy::case_sensitive_file_extension_comparisons)]
+fn resolve_url(context: &CompileContext, destination: &str) -> String {
+    if let Some(url) = context.handler.resolve_url(destination) {
+        url
+    } else if destination.ends_with(".md")
+        && !destination.contains("://")
+        && !destination.starts_with("http")
     {
-        let mut new_buf = buf.clone();
-        new_buf.truncate(new_buf.len() - 3);
-        new_buf.push_str(".html");
-        new_buf
+        format!("{}.html", &destination[..destination.len() - 3])
     } else {
-        buf
-    };
-
-    context.media_stack.last_mut().unwrap().destination = Some(processed_buf);
-    context.encode_html = true;
+        destination.to_string()
+    }
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`ResourceTitleString`][Name::ResourceTitleString].
@@ -1594,7 +2113,10 @@ fn on_exit_strong(context: &mut CompileContext) {
 /// Handle [`Exit`][Kind::Exit]:[`ThematicBreak`][Name::ThematicBreak].
 fn on_exit_thematic_break(context: &mut CompileContext) {
     context.line_ending_if_needed();
-    context.push("<hr class=\"uk-divider-icon mt-4\"/>");
+    let options = context.options;
+    context.push("<hr");
+    push_class(context, &options.frankenui.classes.thematic_break);
+    context.push("/>");
 }
 
 /// Generate a footnote section.
@@ -404,6 +404,8 @@ fn exit(context: &mut CompileContext) -> Result<(), message::Message> {
             on_exit_gfm_task_list_item_value(context);
         }
         Name::HardBreakEscape | Name::HardBreakTrailing => on_exit_hard_break(context)?,
+        Name::HeadingAttributesClass => on_exit_heading_attributes_class(context),
+        Name::HeadingAttributesId => on_exit_heading_attributes_id(context),
         Name::HeadingAtxSequence => on_exit_heading_atx_sequence(context),
         Name::HeadingSetext => on_exit_heading_setext(context)?,
         Name::HeadingSetextUnderlineSequence => on_exit_heading_setext_underline_sequence(context),
@@ -706,6 +708,8 @@ fn on_enter_heading(context: &mut CompileContext) {
         depth: 0, // Will be set later.
         children: vec![],
         position: None,
+        id: None,
+        classes: vec![],
     }));
 }
 
@@ -1235,6 +1239,36 @@ fn on_exit_hard_break(context: &mut CompileContext) -> Result<(), message::Messa
     Ok(())
 }
 
+/// Handle [`Exit`][Kind::Exit]:[`HeadingAttributesClass`][Name::HeadingAttributesClass].
+fn on_exit_heading_attributes_class(context: &mut CompileContext) {
+    let value = Slice::from_position(
+        context.bytes,
+        &SlicePosition::from_exit_event(context.events, context.index),
+    )
+    .serialize();
+
+    if let Node::Heading(node) = context.tail_mut() {
+        node.classes.push(value);
+    } else {
+        unreachable!("expected heading on stack");
+    }
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`HeadingAttributesId`][Name::HeadingAttributesId].
+fn on_exit_heading_attributes_id(context: &mut CompileContext) {
+    let value = Slice::from_position(
+        context.bytes,
+        &SlicePosition::from_exit_event(context.events, context.index),
+    )
+    .serialize();
+
+    if let Node::Heading(node) = context.tail_mut() {
+        node.id = Some(value);
+    } else {
+        unreachable!("expected heading on stack");
+    }
+}
+
 /// Handle [`Exit`][Kind::Exit]:[`HeadingAtxSequence`][Name::HeadingAtxSequence].
 fn on_exit_heading_atx_sequence(context: &mut CompileContext) {
     let slice = Slice::from_position(
@@ -41,7 +41,7 @@ pub enum Kind {
 /// In most cases, markdown operates on ASCII bytes.
 /// In a few cases, it is unicode aware, so we need to find an actual char.
 pub fn before_index(bytes: &[u8], index: usize) -> Option<char> {
-    let start = if index < 4 { 0 } else { index - 4 };
+    let start = index.saturating_sub(4);
     String::from_utf8_lossy(&bytes[start..index]).chars().last()
 }
 
@@ -176,6 +176,27 @@ mod tests {
         assert_eq!(classify('a'), Kind::Other, "should classify other");
     }
 
+    #[test]
+    fn test_before_index() {
+        assert_eq!(
+            before_index(b"ab", 0),
+            None,
+            "should support no char before the start"
+        );
+
+        assert_eq!(
+            before_index(b"ab", 1),
+            Some('a'),
+            "should support a char less than 4 bytes in"
+        );
+
+        assert_eq!(
+            before_index("a\u{1F600}b".as_bytes(), 5),
+            Some('\u{1F600}'),
+            "should support a 4 byte char"
+        );
+    }
+
     #[test]
     fn test_format_opt() {
         assert_eq!(
@@ -0,0 +1,390 @@
+use markdown::{
+    message, to_frankenui_document, to_frankenui_document_with_handler, to_mdast, CompileOptions,
+    FrankenUiClasses, FrankenUiHandler, FrankenUiOptions, Options, OutlineHeading,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn frankenui_document() -> Result<(), message::Message> {
+    assert_eq!(
+        to_frankenui_document("# Alpha\n\n## Bravo\n\n### Charlie", &Options::default())?.outline,
+        vec![
+            OutlineHeading {
+                text: "Alpha".into(),
+                depth: 1,
+                id: "alpha".into()
+            },
+            OutlineHeading {
+                text: "Bravo".into(),
+                depth: 2,
+                id: "bravo".into()
+            },
+            OutlineHeading {
+                text: "Charlie".into(),
+                depth: 3,
+                id: "charlie".into()
+            }
+        ],
+        "should support an outline of heading (atx)s"
+    );
+
+    assert_eq!(
+        to_frankenui_document("Alpha\n=====\n\nBravo\n-----", &Options::default())?.outline,
+        vec![
+            OutlineHeading {
+                text: "Alpha".into(),
+                depth: 1,
+                id: "alpha".into()
+            },
+            OutlineHeading {
+                text: "Bravo".into(),
+                depth: 2,
+                id: "bravo".into()
+            }
+        ],
+        "should support an outline of heading (setext)s"
+    );
+
+    assert_eq!(
+        to_frankenui_document("# Hi *Earth* & `Mars`!", &Options::default())?.outline,
+        vec![OutlineHeading {
+            text: "Hi Earth & Mars!".into(),
+            depth: 1,
+            id: "hi-earth-mars".into()
+        }],
+        "should use the plain text of headings w/ phrasing content"
+    );
+
+    let document = to_frankenui_document("# Alpha\n\nBravo\n-----", &Options::default())?;
+    for heading in &document.outline {
+        assert!(
+            document.html.contains(&format!("id=\"{}\"", heading.id)),
+            "should write the outline id `{}` into the HTML",
+            heading.id
+        );
+    }
+
+    let ids = |value: &str| -> Result<Vec<String>, message::Message> {
+        Ok(to_frankenui_document(value, &Options::default())?
+            .outline
+            .into_iter()
+            .map(|heading| heading.id)
+            .collect())
+    };
+
+    assert_eq!(
+        ids("# Example\n\n## Example\n\nExample\n-------")?,
+        vec!["example", "example-1", "example-2"],
+        "should suffix ids that are taken"
+    );
+
+    assert_eq!(
+        ids("# Example 1\n\n# Example\n\n# Example")?,
+        vec!["example-1", "example", "example-2"],
+        "should skip suffixed ids that are taken"
+    );
+
+    assert_eq!(
+        ids("# Example\n\n# Example\n\n# Example 1")?,
+        vec!["example", "example-1", "example-1-1"],
+        "should suffix ids taken by suffixed ids"
+    );
+
+    assert_eq!(
+        ids("# *\n\n# *")?,
+        vec!["", ""],
+        "should not suffix empty ids"
+    );
+
+    assert_eq!(
+        to_frankenui_document("## a\n\n## a", &Options::default())?.html,
+        "<h2 class=\"uk-h2 mt-8 mb-4\" id=\"a\">a</h2>\n<hr class=\"uk-hr mb-6\"/>\n<h2 class=\"uk-h2 mt-8 mb-4\" id=\"a-1\">a</h2>\n<hr class=\"uk-hr mb-6\"/>",
+        "should write unique ids into the HTML"
+    );
+
+    assert_eq!(
+        to_frankenui_document("*a*\n\n# b", &Options::default())?.mdast,
+        to_mdast("*a*\n\n# b", &Default::default())?,
+        "should include the same tree as `to_mdast`"
+    );
+
+    assert_eq!(
+        to_frankenui_document("a", &Options::default())?.outline,
+        vec![],
+        "should support an empty outline"
+    );
+
+    Ok(())
+}
+
+struct Assets;
+
+impl FrankenUiHandler for Assets {
+    fn resolve_url(&self, url: &str) -> Option<String> {
+        url.strip_prefix("./")
+            .map(|path| format!("/assets/{}", path))
+    }
+}
+
+#[test]
+fn frankenui_document_handler() -> Result<(), message::Message> {
+    assert_eq!(
+        to_frankenui_document("[a](b.md) [c](https://d.md)", &Options::default())?.html,
+        "<p class=\"uk-paragraph\"><a class=\"uk-link\" href=\"b.html\">a</a> <a class=\"uk-link\" href=\"https://d.md\">c</a></p>",
+        "should turn relative links to `.md` files into `.html` links by default"
+    );
+
+    assert_eq!(
+        to_frankenui_document_with_handler("![a](./b.png)", &Options::default(), &Assets)?.html,
+        "<p class=\"uk-paragraph\"><img src=\"/assets/b.png\" alt=\"a\" /></p>",
+        "should support a handler resolving image sources"
+    );
+
+    assert_eq!(
+        to_frankenui_document_with_handler("[a]\n\n[a]: ./b.pdf", &Options::default(), &Assets)?
+            .html,
+        "<p class=\"uk-paragraph\"><a class=\"uk-link\" href=\"/assets/b.pdf\">a</a></p>\n",
+        "should support a handler resolving definitions"
+    );
+
+    assert_eq!(
+        to_frankenui_document_with_handler("[a](b.md)", &Options::default(), &Assets)?.html,
+        "<p class=\"uk-paragraph\"><a class=\"uk-link\" href=\"b.html\">a</a></p>",
+        "should fall back to the default when a handler does not resolve a url"
+    );
+
+    Ok(())
+}
+
+struct Marks;
+
+impl FrankenUiHandler for Marks {
+    fn highlight_code(&self, language: &str, code: &str) -> Option<String> {
+        (language == "rust").then(|| {
+            code.split_inclusive('\n')
+                .map(|line| {
+                    let text = line.trim_end_matches('\n');
+                    format!(
+                        "<mark>{}</mark>{}",
+                        text.replace('<', "&lt;"),
+                        &line[text.len()..]
+                    )
+                })
+                .collect()
+        })
+    }
+}
+
+#[test]
+fn frankenui_document_highlight() -> Result<(), message::Message> {
+    assert_eq!(
+        to_frankenui_document_with_handler("```rust\nlet a = b < c;\nd();\n```", &Options::default(), &Marks)?
+            .html,
+        "<pre class=\"mt-4\"><code class=\"language-rust\"><mark>let a = b &lt; c;</mark>\n<mark>d();</mark>\n</code></pre>",
+        "should support a handler highlighting fenced code w/ the code as written"
+    );
+
+    assert_eq!(
+        to_frankenui_document_with_handler("```toml\na = 1\n```", &Options::default(), &Marks)?
+            .html,
+        "<pre class=\"mt-4\"><code class=\"language-toml\">a = 1\n</code></pre>",
+        "should fall back to plain code when a handler does not highlight a language"
+    );
+
+    assert_eq!(
+        to_frankenui_document_with_handler("```\nlet a;\n```\n\n    let b;", &Options::default(), &Marks)?
+            .html,
+        "<pre class=\"mt-4\"><code>let a;\n</code></pre>\n<pre class=\"mt-4\"><code>let b;\n</code></pre>",
+        "should not highlight code w/o language"
+    );
+
+    assert_eq!(
+        to_frankenui_document_with_handler("> ```rust\n> a\n\nb", &Options::default(), &Marks)?.html,
+        "<blockquote class=\"uk-blockquote mt-4\">\n<pre class=\"mt-4\"><code class=\"language-rust\"><mark>a</mark>\n</code></pre>\n</blockquote>\n<p class=\"uk-paragraph\">b</p>",
+        "should support unclosed fenced code in a container"
+    );
+
+    assert_eq!(
+        to_frankenui_document_with_handler("```rust\n```", &Options::default(), &Marks)?.html,
+        "<pre class=\"mt-4\"><code class=\"language-rust\"></code></pre>",
+        "should support empty fenced code"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn frankenui_document_fence_meta() -> Result<(), message::Message> {
+    assert_eq!(
+        to_frankenui_document("```rust title=\"src/main.rs\"\nfn main() {}\n```", &Options::default())?
+            .html,
+        "<div class=\"uk-card mt-4\"><div class=\"uk-card-header px-4 py-2 font-mono text-sm\">src/main.rs</div><pre class=\"m-0\"><code class=\"language-rust\">fn main() {}\n</code></pre></div>",
+        "should support a title in a card header"
+    );
+
+    assert_eq!(
+        to_frankenui_document("```js title='a <b>.js' x\na\n```", &Options::default())?.html,
+        "<div class=\"uk-card mt-4\"><div class=\"uk-card-header px-4 py-2 font-mono text-sm\">a &lt;b&gt;.js</div><pre class=\"m-0\"><code class=\"language-js\">a\n</code></pre></div>",
+        "should support single quoted titles w/ whitespace, and ignore unknown words"
+    );
+
+    assert_eq!(
+        to_frankenui_document("```txt {1,3-4}\na\nb\nc\nd\ne\n```", &Options::default())?.html,
+        "<pre class=\"mt-4\"><code class=\"language-txt\" data-lines=\"\"><span class=\"code-line code-line-highlighted\">a</span>\n<span class=\"code-line\">b</span>\n<span class=\"code-line code-line-highlighted\">c</span>\n<span class=\"code-line code-line-highlighted\">d</span>\n<span class=\"code-line\">e</span>\n</code></pre>",
+        "should support highlighted lines and ranges"
+    );
+
+    assert_eq!(
+        to_frankenui_document("```text showLineNumbers\na < b\n\n```", &Options::default())?.html,
+        "<pre class=\"mt-4\"><code class=\"language-text\" data-lines=\"\"><span class=\"code-line\"><span class=\"code-line-number\" aria-hidden=\"true\">1</span>a &lt; b</span>\n<span class=\"code-line\"><span class=\"code-line-number\" aria-hidden=\"true\">2</span></span>\n</code></pre>",
+        "should support line numbers w/ blank lines"
+    );
+
+    assert_eq!(
+        to_frankenui_document_with_handler(
+            "```rust title=\"src/main.rs\" {2} showLineNumbers\nlet a;\nlet b;\n```",
+            &Options::default(),
+            &Marks
+        )?
+        .html,
+        "<div class=\"uk-card mt-4\"><div class=\"uk-card-header px-4 py-2 font-mono text-sm\">src/main.rs</div><pre class=\"m-0\"><code class=\"language-rust\" data-lines=\"\"><span class=\"code-line\"><span class=\"code-line-number\" aria-hidden=\"true\">1</span><mark>let a;</mark></span>\n<span class=\"code-line code-line-highlighted\"><span class=\"code-line-number\" aria-hidden=\"true\">2</span><mark>let b;</mark></span>\n</code></pre></div>",
+        "should wrap the lines of highlighted code"
+    );
+
+    assert_eq!(
+        to_frankenui_document(
+            "```rust {a,2-} showlinenumbers\na\n```",
+            &Options::default()
+        )?
+        .html,
+        "<pre class=\"mt-4\"><code class=\"language-rust\">a\n</code></pre>",
+        "should ignore invalid line ranges and words"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn frankenui_document_code_toolbar() -> Result<(), message::Message> {
+    let toolbar = Options {
+        compile: CompileOptions {
+            frankenui: FrankenUiOptions {
+                code_language_badge: true,
+                code_copy_button: true,
+                ..FrankenUiOptions::default()
+            },
+            ..CompileOptions::default()
+        },
+        ..Options::default()
+    };
+
+    assert_eq!(
+        to_frankenui_document("```rust\na\n```", &Options::default())?.html,
+        "<pre class=\"mt-4\"><code class=\"language-rust\">a\n</code></pre>",
+        "should not add a badge or copy button by default"
+    );
+
+    assert_eq!(
+        to_frankenui_document("```rust\na\n```", &toolbar)?.html,
+        "<div class=\"relative mt-4\"><div class=\"absolute right-2 top-2 flex items-center gap-2\"><span class=\"uk-badge uk-badge-secondary\">rust</span><button type=\"button\" class=\"uk-btn uk-btn-default uk-btn-xs uk-btn-icon\" data-copy-code=\"\" aria-label=\"Copy code\"><uk-icon icon=\"copy\"></uk-icon></button></div><pre class=\"m-0\"><code class=\"language-rust\">a\n</code></pre></div>",
+        "should support a language badge and copy button"
+    );
+
+    assert_eq!(
+        to_frankenui_document("```rust nocopy\na\n```", &toolbar)?.html,
+        "<div class=\"relative mt-4\"><div class=\"absolute right-2 top-2 flex items-center gap-2\"><span class=\"uk-badge uk-badge-secondary\">rust</span></div><pre class=\"m-0\"><code class=\"language-rust\">a\n</code></pre></div>",
+        "should support `nocopy` in the meta"
+    );
+
+    assert_eq!(
+        to_frankenui_document("```js title=\"a.js\"\na\n```", &toolbar)?.html,
+        "<div class=\"uk-card mt-4\"><div class=\"uk-card-header px-4 py-2 font-mono text-sm\">a.js</div><div class=\"relative\"><div class=\"absolute right-2 top-2 flex items-center gap-2\"><span class=\"uk-badge uk-badge-secondary\">js</span><button type=\"button\" class=\"uk-btn uk-btn-default uk-btn-xs uk-btn-icon\" data-copy-code=\"\" aria-label=\"Copy code\"><uk-icon icon=\"copy\"></uk-icon></button></div><pre class=\"m-0\"><code class=\"language-js\">a\n</code></pre></div></div>",
+        "should support a badge and copy button w/ a title"
+    );
+
+    assert_eq!(
+        to_frankenui_document("```\na\n```\n\n    b", &toolbar)?.html,
+        "<div class=\"relative mt-4\"><div class=\"absolute right-2 top-2 flex items-center gap-2\"><button type=\"button\" class=\"uk-btn uk-btn-default uk-btn-xs uk-btn-icon\" data-copy-code=\"\" aria-label=\"Copy code\"><uk-icon icon=\"copy\"></uk-icon></button></div><pre class=\"m-0\"><code>a\n</code></pre></div>\n<pre class=\"mt-4\"><code>b\n</code></pre>",
+        "should support a copy button w/o language, and not on indented code"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn frankenui_document_classes() -> Result<(), message::Message> {
+    let classes = |classes: FrankenUiClasses| Options {
+        compile: CompileOptions {
+            frankenui: FrankenUiOptions {
+                classes,
+                ..FrankenUiOptions::default()
+            },
+            ..CompileOptions::default()
+        },
+        ..Options::gfm()
+    };
+
+    assert_eq!(
+        to_frankenui_document(
+            "| a |\n| - |\n| b |",
+            &classes(FrankenUiClasses {
+                table: "uk-table uk-table-striped".into(),
+                ..FrankenUiClasses::default()
+            })
+        )?
+        .html,
+        "<div class=\"uk-overflow-auto mt-4\"><table class=\"uk-table uk-table-striped\">\n<thead>\n<tr>\n<th>a</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>b</td>\n</tr>\n</tbody>\n</table></div>",
+        "should support custom classes"
+    );
+
+    assert_eq!(
+        to_frankenui_document(
+            "a *b*\n\n### c",
+            &classes(FrankenUiClasses {
+                paragraph: String::new(),
+                emphasis: String::new(),
+                heading_3: "uk-h3 mt-12".into(),
+                ..FrankenUiClasses::default()
+            })
+        )?
+        .html,
+        "<p>a <em>b</em></p>\n<h3 class=\"uk-h3 mt-12\" id=\"c\">c</h3>",
+        "should leave out `class` attributes w/o classes"
+    );
+
+    assert_eq!(
+        to_frankenui_document(
+            "```js title=\"a.js\" {1}\na\n```",
+            &classes(FrankenUiClasses {
+                code_block: "my-6".into(),
+                code_block_card: String::new(),
+                code_line_highlighted: "line marked".into(),
+                ..FrankenUiClasses::default()
+            })
+        )?
+        .html,
+        "<div class=\"my-6\"><div class=\"uk-card-header px-4 py-2 font-mono text-sm\">a.js</div><pre class=\"m-0\"><code class=\"language-js\" data-lines=\"\"><span class=\"line marked\">a</span>\n</code></pre></div>",
+        "should support custom classes of code blocks"
+    );
+
+    assert_eq!(
+        to_frankenui_document(
+            "$a$",
+            &Options {
+                parse: markdown::ParseOptions {
+                    constructs: markdown::Constructs {
+                        math_text: true,
+                        ..markdown::Constructs::default()
+                    },
+                    ..markdown::ParseOptions::default()
+                },
+                ..Options::default()
+            }
+        )?
+        .html,
+        "<p class=\"uk-paragraph\"><code class=\"uk-codespan language-math math-inline\">a</code></p>",
+        "should join the classes of math (text)"
+    );
+
+    Ok(())
+}
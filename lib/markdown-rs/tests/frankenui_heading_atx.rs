@@ -0,0 +1,258 @@
+use markdown::{
+    message, to_frankenui_document, CompileOptions, Constructs, FrankenUiOptions, Options,
+    ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn frankenui_heading_atx() -> Result<(), message::Message> {
+    assert_eq!(
+        to_frankenui_document("# foo", &Options::default())?.html,
+        "<h1 class=\"uk-h1 mt-8 mb-4\" id=\"foo\">foo</h1>\n<hr class=\"uk-hr mb-6\"/>",
+        "should support a heading w/ rank 1"
+    );
+
+    assert_eq!(
+        to_frankenui_document("## foo", &Options::default())?.html,
+        "<h2 class=\"uk-h2 mt-8 mb-4\" id=\"foo\">foo</h2>\n<hr class=\"uk-hr mb-6\"/>",
+        "should support a heading w/ rank 2"
+    );
+
+    assert_eq!(
+        to_frankenui_document("### foo", &Options::default())?.html,
+        "<h3 class=\"uk-h3 mt-8 mb-4\" id=\"foo\">foo</h3>",
+        "should support a heading w/ rank 3"
+    );
+
+    assert_eq!(
+        to_frankenui_document("#### foo", &Options::default())?.html,
+        "<h4 class=\"uk-h4 mt-8 mb-4\" id=\"foo\">foo</h4>",
+        "should support a heading w/ rank 4"
+    );
+
+    assert_eq!(
+        to_frankenui_document("##### foo", &Options::default())?.html,
+        "<h5 class=\"uk-h5 mt-8 mb-4\" id=\"foo\">foo</h5>",
+        "should support a heading w/ rank 5"
+    );
+
+    assert_eq!(
+        to_frankenui_document("###### foo", &Options::default())?.html,
+        "<h6 class=\"uk-h6 mt-8 mb-4\" id=\"foo\">foo</h6>",
+        "should support a heading w/ rank 6"
+    );
+
+    assert_eq!(
+        to_frankenui_document("####### foo", &Options::default())?.html,
+        "<p class=\"uk-paragraph\">####### foo</p>",
+        "should not support a heading w/ rank 7"
+    );
+
+    assert_eq!(
+        to_frankenui_document("#5 bolt", &Options::default())?.html,
+        "<p class=\"uk-paragraph\">#5 bolt</p>",
+        "should not support a heading for a number sign not followed by whitespace (1)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("#hashtag", &Options::default())?.html,
+        "<p class=\"uk-paragraph\">#hashtag</p>",
+        "should not support a heading for a number sign not followed by whitespace (2)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("\\## foo", &Options::default())?.html,
+        "<p class=\"uk-paragraph\">## foo</p>",
+        "should not support a heading for an escaped number sign"
+    );
+
+    assert_eq!(
+        to_frankenui_document("# foo *bar* \\*baz\\*", &Options::default())?.html,
+        "<h1 class=\"uk-h1 mt-8 mb-4\" id=\"foo-bar-baz\">foo <em class=\"italic\">bar</em> *baz*</h1>\n<hr class=\"uk-hr mb-6\"/>",
+        "should support text content in headings"
+    );
+
+    assert_eq!(
+        to_frankenui_document(
+            "#                  foo                     ",
+            &Options::default()
+        )?
+        .html,
+        "<h1 class=\"uk-h1 mt-8 mb-4\" id=\"foo\">foo</h1>\n<hr class=\"uk-hr mb-6\"/>",
+        "should support arbitrary initial and final whitespace"
+    );
+
+    assert_eq!(
+        to_frankenui_document(" ### foo", &Options::default())?.html,
+        "<h3 class=\"uk-h3 mt-8 mb-4\" id=\"foo\">foo</h3>",
+        "should support an initial space"
+    );
+
+    assert_eq!(
+        to_frankenui_document("  ## foo", &Options::default())?.html,
+        "<h2 class=\"uk-h2 mt-8 mb-4\" id=\"foo\">foo</h2>\n<hr class=\"uk-hr mb-6\"/>",
+        "should support two initial spaces"
+    );
+
+    assert_eq!(
+        to_frankenui_document("   # foo", &Options::default())?.html,
+        "<h1 class=\"uk-h1 mt-8 mb-4\" id=\"foo\">foo</h1>\n<hr class=\"uk-hr mb-6\"/>",
+        "should support three initial spaces"
+    );
+
+    assert_eq!(
+        to_frankenui_document("    # foo", &Options::default())?.html,
+        "<pre class=\"mt-4\"><code># foo\n</code></pre>",
+        "should not support four initial spaces"
+    );
+
+    assert_eq!(
+        to_frankenui_document("foo\n    # bar", &Options::default())?.html,
+        "<p class=\"uk-paragraph\">foo\n# bar</p>",
+        "should not support four initial spaces when interrupting"
+    );
+
+    assert_eq!(
+        to_frankenui_document("## foo ##", &Options::default())?.html,
+        "<h2 class=\"uk-h2 mt-8 mb-4\" id=\"foo\">foo</h2>\n<hr class=\"uk-hr mb-6\"/>",
+        "should support a closing sequence (1)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("  ###   bar    ###", &Options::default())?.html,
+        "<h3 class=\"uk-h3 mt-8 mb-4\" id=\"bar\">bar</h3>",
+        "should support a closing sequence (2)"
+    );
+
+    assert_eq!(
+        to_frankenui_document(
+            "# foo ##################################",
+            &Options::default()
+        )?
+        .html,
+        "<h1 class=\"uk-h1 mt-8 mb-4\" id=\"foo\">foo</h1>\n<hr class=\"uk-hr mb-6\"/>",
+        "should support a closing sequence w/ an arbitrary number of number signs (1)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("##### foo ##", &Options::default())?.html,
+        "<h5 class=\"uk-h5 mt-8 mb-4\" id=\"foo\">foo</h5>",
+        "should support a closing sequence w/ an arbitrary number of number signs (2)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("### foo ###     ", &Options::default())?.html,
+        "<h3 class=\"uk-h3 mt-8 mb-4\" id=\"foo\">foo</h3>",
+        "should support trailing whitespace after a closing sequence"
+    );
+
+    assert_eq!(
+        to_frankenui_document("### foo ### b", &Options::default())?.html,
+        "<h3 class=\"uk-h3 mt-8 mb-4\" id=\"foo-b\">foo ### b</h3>",
+        "should not support other content after a closing sequence"
+    );
+
+    assert_eq!(
+        to_frankenui_document("# foo#", &Options::default())?.html,
+        "<h1 class=\"uk-h1 mt-8 mb-4\" id=\"foo\">foo#</h1>\n<hr class=\"uk-hr mb-6\"/>",
+        "should not support a closing sequence w/o whitespace before it"
+    );
+
+    assert_eq!(
+        to_frankenui_document("### foo \\###", &Options::default())?.html,
+        "<h3 class=\"uk-h3 mt-8 mb-4\" id=\"foo\">foo ###</h3>",
+        "should not support an “escaped” closing sequence (1)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("## foo #\\##", &Options::default())?.html,
+        "<h2 class=\"uk-h2 mt-8 mb-4\" id=\"foo\">foo ###</h2>\n<hr class=\"uk-hr mb-6\"/>",
+        "should not support an “escaped” closing sequence (2)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("# foo \\#", &Options::default())?.html,
+        "<h1 class=\"uk-h1 mt-8 mb-4\" id=\"foo\">foo #</h1>\n<hr class=\"uk-hr mb-6\"/>",
+        "should not support an “escaped” closing sequence (3)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("****\n## foo\n****", &Options::default())?.html,
+        "<hr class=\"uk-divider-icon mt-4\"/>\n<h2 class=\"uk-h2 mt-8 mb-4\" id=\"foo\">foo</h2>\n<hr class=\"uk-hr mb-6\"/>\n<hr class=\"uk-divider-icon mt-4\"/>",
+        "should support atx headings when not surrounded by blank lines"
+    );
+
+    assert_eq!(
+        to_frankenui_document("Foo bar\n# baz\nBar foo", &Options::default())?.html,
+        "<p class=\"uk-paragraph\">Foo bar</p>\n<h1 class=\"uk-h1 mt-8 mb-4\" id=\"baz\">baz</h1>\n<hr class=\"uk-hr mb-6\"/>\n<p class=\"uk-paragraph\">Bar foo</p>",
+        "should support atx headings interrupting paragraphs"
+    );
+
+    assert_eq!(
+        to_frankenui_document("## \n#\n### ###", &Options::default())?.html,
+        "<h2 class=\"uk-h2 mt-8 mb-4\"></h2>\n<hr class=\"uk-hr mb-6\"/>\n<h1 class=\"uk-h1 mt-8 mb-4\"></h1>\n<hr class=\"uk-hr mb-6\"/>\n<h3 class=\"uk-h3 mt-8 mb-4\"></h3>",
+        "should support empty atx headings (1)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("#\na\n# b", &Options::default())?.html,
+        "<h1 class=\"uk-h1 mt-8 mb-4\"></h1>\n<hr class=\"uk-hr mb-6\"/>\n<p class=\"uk-paragraph\">a</p>\n<h1 class=\"uk-h1 mt-8 mb-4\" id=\"b\">b</h1>\n<hr class=\"uk-hr mb-6\"/>",
+        "should support empty atx headings (2)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("> #\na", &Options::default())?.html,
+        "<blockquote class=\"uk-blockquote mt-4\">\n<h1 class=\"uk-h1 mt-8 mb-4\"></h1>\n<hr class=\"uk-hr mb-6\"/>\n</blockquote>\n<p class=\"uk-paragraph\">a</p>",
+        "should not support lazyness (1)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("> a\n#", &Options::default())?.html,
+        "<blockquote class=\"uk-blockquote mt-4\">\n<p class=\"uk-paragraph\">a</p>\n</blockquote>\n<h1 class=\"uk-h1 mt-8 mb-4\"></h1>\n<hr class=\"uk-hr mb-6\"/>",
+        "should not support lazyness (2)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("# a\n## b\n### c", &Options::default())?.html,
+        "<h1 class=\"uk-h1 mt-8 mb-4\" id=\"a\">a</h1>\n<hr class=\"uk-hr mb-6\"/>\n<h2 class=\"uk-h2 mt-8 mb-4\" id=\"b\">b</h2>\n<hr class=\"uk-hr mb-6\"/>\n<h3 class=\"uk-h3 mt-8 mb-4\" id=\"c\">c</h3>",
+        "should support a rule after headings w/ rank 1 and 2"
+    );
+
+    let no_heading_rule = Options {
+        compile: CompileOptions {
+            frankenui: FrankenUiOptions {
+                heading_rule: false,
+                ..FrankenUiOptions::default()
+            },
+            ..CompileOptions::default()
+        },
+        ..Options::default()
+    };
+
+    assert_eq!(
+        to_frankenui_document("# a\n## b\n### c", &no_heading_rule)?.html,
+        "<h1 class=\"uk-h1 mt-8 mb-4\" id=\"a\">a</h1>\n<h2 class=\"uk-h2 mt-8 mb-4\" id=\"b\">b</h2>\n<h3 class=\"uk-h3 mt-8 mb-4\" id=\"c\">c</h3>",
+        "should support turning off the rule after headings w/ rank 1 and 2"
+    );
+
+    assert_eq!(
+        to_frankenui_document(
+            "# a",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        heading_atx: false,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?
+        .html,
+        "<p class=\"uk-paragraph\"># a</p>",
+        "should support turning off heading (atx)"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,318 @@
+use markdown::{
+    message, to_frankenui_document, CompileOptions, Constructs, FrankenUiOptions, Options,
+    ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn frankenui_heading_setext() -> Result<(), message::Message> {
+    assert_eq!(
+        to_frankenui_document("Foo *bar*\n=========", &Options::default())?.html,
+        "<h1 class=\"uk-h1 mt-8 mb-4\" id=\"foo-bar\">Foo <em class=\"italic\">bar</em></h1>\n<hr class=\"uk-hr mb-6\"/>",
+        "should support a heading w/ an equals to (rank of 1)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("Foo *bar*\n---------", &Options::default())?.html,
+        "<h2 class=\"uk-h2 mt-8 mb-4\" id=\"foo-bar\">Foo <em class=\"italic\">bar</em></h2>\n<hr class=\"uk-hr mb-6\"/>",
+        "should support a heading w/ a dash (rank of 2)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("Foo *bar\nbaz*\n====", &Options::default())?.html,
+        "<h1 class=\"uk-h1 mt-8 mb-4\" id=\"foo-bar-baz\">Foo <em class=\"italic\">bar\nbaz</em></h1>\n<hr class=\"uk-hr mb-6\"/>",
+        "should support line endings in setext headings"
+    );
+
+    assert_eq!(
+        to_frankenui_document("  Foo *bar\nbaz*\t\n====", &Options::default())?.html,
+        "<h1 class=\"uk-h1 mt-8 mb-4\" id=\"foo-bar-baz\">Foo <em class=\"italic\">bar\nbaz</em></h1>\n<hr class=\"uk-hr mb-6\"/>",
+        "should not include initial and final whitespace around content"
+    );
+
+    assert_eq!(
+        to_frankenui_document("Foo\n-------------------------", &Options::default())?.html,
+        "<h2 class=\"uk-h2 mt-8 mb-4\" id=\"foo\">Foo</h2>\n<hr class=\"uk-hr mb-6\"/>",
+        "should support long underlines"
+    );
+
+    assert_eq!(
+        to_frankenui_document("Foo\n=", &Options::default())?.html,
+        "<h1 class=\"uk-h1 mt-8 mb-4\" id=\"foo\">Foo</h1>\n<hr class=\"uk-hr mb-6\"/>",
+        "should support short underlines"
+    );
+
+    assert_eq!(
+        to_frankenui_document(" Foo\n  ===", &Options::default())?.html,
+        "<h1 class=\"uk-h1 mt-8 mb-4\" id=\"foo\">Foo</h1>\n<hr class=\"uk-hr mb-6\"/>",
+        "should support indented content w/ 1 space"
+    );
+
+    assert_eq!(
+        to_frankenui_document("  Foo\n---", &Options::default())?.html,
+        "<h2 class=\"uk-h2 mt-8 mb-4\" id=\"foo\">Foo</h2>\n<hr class=\"uk-hr mb-6\"/>",
+        "should support indented content w/ 2 spaces"
+    );
+
+    assert_eq!(
+        to_frankenui_document("   Foo\n---", &Options::default())?.html,
+        "<h2 class=\"uk-h2 mt-8 mb-4\" id=\"foo\">Foo</h2>\n<hr class=\"uk-hr mb-6\"/>",
+        "should support indented content w/ 3 spaces"
+    );
+
+    assert_eq!(
+        to_frankenui_document("    Foo\n    ---", &Options::default())?.html,
+        "<pre class=\"mt-4\"><code>Foo\n---\n</code></pre>",
+        "should not support too much indented content (1)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("    Foo\n---", &Options::default())?.html,
+        "<pre class=\"mt-4\"><code>Foo\n</code></pre>\n<hr class=\"uk-divider-icon mt-4\"/>",
+        "should not support too much indented content (2)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("Foo\n   ----      ", &Options::default())?.html,
+        "<h2 class=\"uk-h2 mt-8 mb-4\" id=\"foo\">Foo</h2>\n<hr class=\"uk-hr mb-6\"/>",
+        "should support initial and final whitespace around the underline"
+    );
+
+    assert_eq!(
+        to_frankenui_document("Foo\n   =", &Options::default())?.html,
+        "<h1 class=\"uk-h1 mt-8 mb-4\" id=\"foo\">Foo</h1>\n<hr class=\"uk-hr mb-6\"/>",
+        "should support whitespace before underline"
+    );
+
+    assert_eq!(
+        to_frankenui_document("Foo\n    =", &Options::default())?.html,
+        "<p class=\"uk-paragraph\">Foo\n=</p>",
+        "should not support too much whitespace before underline (1)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("Foo\n\t=", &Options::default())?.html,
+        "<p class=\"uk-paragraph\">Foo\n=</p>",
+        "should not support too much whitespace before underline (2)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("Foo\n= =", &Options::default())?.html,
+        "<p class=\"uk-paragraph\">Foo\n= =</p>",
+        "should not support whitespace in the underline (1)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("Foo\n--- -", &Options::default())?.html,
+        "<p class=\"uk-paragraph\">Foo</p>\n<hr class=\"uk-divider-icon mt-4\"/>",
+        "should not support whitespace in the underline (2)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("Foo  \n-----", &Options::default())?.html,
+        "<h2 class=\"uk-h2 mt-8 mb-4\" id=\"foo\">Foo</h2>\n<hr class=\"uk-hr mb-6\"/>",
+        "should not support a hard break w/ spaces at the end"
+    );
+
+    assert_eq!(
+        to_frankenui_document("Foo\\\n-----", &Options::default())?.html,
+        "<h2 class=\"uk-h2 mt-8 mb-4\" id=\"foo\">Foo\\</h2>\n<hr class=\"uk-hr mb-6\"/>",
+        "should not support a hard break w/ backslash at the end"
+    );
+
+    assert_eq!(
+        to_frankenui_document("`Foo\n----\n`", &Options::default())?.html,
+        "<h2 class=\"uk-h2 mt-8 mb-4\" id=\"foo\">`Foo</h2>\n<hr class=\"uk-hr mb-6\"/>\n<p class=\"uk-paragraph\">`</p>",
+        "should precede over inline constructs (1)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("<a title=\"a lot\n---\nof dashes\"/>", &Options::default())?.html,
+        "<h2 class=\"uk-h2 mt-8 mb-4\" id=\"a-title-a-lot\">&lt;a title=&quot;a lot</h2>\n<hr class=\"uk-hr mb-6\"/>\n<p class=\"uk-paragraph\">of dashes&quot;/&gt;</p>",
+        "should precede over inline constructs (2)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("> Foo\n---", &Options::default())?.html,
+        "<blockquote class=\"uk-blockquote mt-4\">\n<p class=\"uk-paragraph\">Foo</p>\n</blockquote>\n<hr class=\"uk-divider-icon mt-4\"/>",
+        "should not allow underline to be lazy (1)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("> foo\nbar\n===", &Options::default())?.html,
+        "<blockquote class=\"uk-blockquote mt-4\">\n<p class=\"uk-paragraph\">foo\nbar\n===</p>\n</blockquote>",
+        "should not allow underline to be lazy (2)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("- Foo\n---", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>Foo</li>\n</ul>\n<hr class=\"uk-divider-icon mt-4\"/>",
+        "should not allow underline to be lazy (3)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("Foo\nBar\n---", &Options::default())?.html,
+        "<h2 class=\"uk-h2 mt-8 mb-4\" id=\"foo-bar\">Foo\nBar</h2>\n<hr class=\"uk-hr mb-6\"/>",
+        "should support line endings in setext headings"
+    );
+
+    assert_eq!(
+        to_frankenui_document("---\nFoo\n---\nBar\n---\nBaz", &Options::default())?.html,
+        "<hr class=\"uk-divider-icon mt-4\"/>\n<h2 class=\"uk-h2 mt-8 mb-4\" id=\"foo\">Foo</h2>\n<hr class=\"uk-hr mb-6\"/>\n<h2 class=\"uk-h2 mt-8 mb-4\" id=\"bar\">Bar</h2>\n<hr class=\"uk-hr mb-6\"/>\n<p class=\"uk-paragraph\">Baz</p>",
+        "should support adjacent setext headings"
+    );
+
+    assert_eq!(
+        to_frankenui_document("\n====", &Options::default())?.html,
+        "<p class=\"uk-paragraph\">====</p>",
+        "should not support empty setext headings"
+    );
+
+    assert_eq!(
+        to_frankenui_document("---\n---", &Options::default())?.html,
+        "<hr class=\"uk-divider-icon mt-4\"/>\n<hr class=\"uk-divider-icon mt-4\"/>",
+        "should prefer other constructs over setext headings (1)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("- foo\n-----", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>foo</li>\n</ul>\n<hr class=\"uk-divider-icon mt-4\"/>",
+        "should prefer other constructs over setext headings (2)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("    foo\n---", &Options::default())?.html,
+        "<pre class=\"mt-4\"><code>foo\n</code></pre>\n<hr class=\"uk-divider-icon mt-4\"/>",
+        "should prefer other constructs over setext headings (3)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("> foo\n-----", &Options::default())?.html,
+        "<blockquote class=\"uk-blockquote mt-4\">\n<p class=\"uk-paragraph\">foo</p>\n</blockquote>\n<hr class=\"uk-divider-icon mt-4\"/>",
+        "should prefer other constructs over setext headings (4)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("\\> foo\n------", &Options::default())?.html,
+        "<h2 class=\"uk-h2 mt-8 mb-4\" id=\"foo\">&gt; foo</h2>\n<hr class=\"uk-hr mb-6\"/>",
+        "should support starting w/ character escapes"
+    );
+
+    assert_eq!(
+        to_frankenui_document("Foo\nbar\n---\nbaz", &Options::default())?.html,
+        "<h2 class=\"uk-h2 mt-8 mb-4\" id=\"foo-bar\">Foo\nbar</h2>\n<hr class=\"uk-hr mb-6\"/>\n<p class=\"uk-paragraph\">baz</p>",
+        "paragraph and heading interplay (1)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("Foo\n\nbar\n---\nbaz", &Options::default())?.html,
+        "<p class=\"uk-paragraph\">Foo</p>\n<h2 class=\"uk-h2 mt-8 mb-4\" id=\"bar\">bar</h2>\n<hr class=\"uk-hr mb-6\"/>\n<p class=\"uk-paragraph\">baz</p>",
+        "paragraph and heading interplay (2)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("Foo\nbar\n\n---\n\nbaz", &Options::default())?.html,
+        "<p class=\"uk-paragraph\">Foo\nbar</p>\n<hr class=\"uk-divider-icon mt-4\"/>\n<p class=\"uk-paragraph\">baz</p>",
+        "paragraph and heading interplay (3)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("Foo\nbar\n* * *\nbaz", &Options::default())?.html,
+        "<p class=\"uk-paragraph\">Foo\nbar</p>\n<hr class=\"uk-divider-icon mt-4\"/>\n<p class=\"uk-paragraph\">baz</p>",
+        "paragraph and heading interplay (4)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("Foo\nbar\n\\---\nbaz", &Options::default())?.html,
+        "<p class=\"uk-paragraph\">Foo\nbar\n---\nbaz</p>",
+        "paragraph and heading interplay (5)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("Foo  \nbar\n-----", &Options::default())?.html,
+        "<h2 class=\"uk-h2 mt-8 mb-4\" id=\"foo-bar\">Foo<br />\nbar</h2>\n<hr class=\"uk-hr mb-6\"/>",
+        "should support a hard break w/ spaces in between"
+    );
+
+    assert_eq!(
+        to_frankenui_document("Foo\\\nbar\n-----", &Options::default())?.html,
+        "<h2 class=\"uk-h2 mt-8 mb-4\" id=\"foo-bar\">Foo<br />\nbar</h2>\n<hr class=\"uk-hr mb-6\"/>",
+        "should support a hard break w/ backslash in between"
+    );
+
+    assert_eq!(
+        to_frankenui_document("a\n-\nb", &Options::default())?.html,
+        "<h2 class=\"uk-h2 mt-8 mb-4\" id=\"a\">a</h2>\n<hr class=\"uk-hr mb-6\"/>\n<p class=\"uk-paragraph\">b</p>",
+        "should prefer a setext heading over an interrupting list"
+    );
+
+    assert_eq!(
+        to_frankenui_document("[a]: b\n=\n=", &Options::default())?.html,
+        "<h1 class=\"uk-h1 mt-8 mb-4\">=</h1>\n<hr class=\"uk-hr mb-6\"/>",
+        "should support a two setext heading underlines after a definition, as a setext heading"
+    );
+
+    assert_eq!(
+        to_frankenui_document("> ===\na", &Options::default())?.html,
+        "<blockquote class=\"uk-blockquote mt-4\">\n<p class=\"uk-paragraph\">===\na</p>\n</blockquote>",
+        "should not support lazyness (1)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("> a\n===", &Options::default())?.html,
+        "<blockquote class=\"uk-blockquote mt-4\">\n<p class=\"uk-paragraph\">a\n===</p>\n</blockquote>",
+        "should not support lazyness (2)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("a\n- ===", &Options::default())?.html,
+        "<p class=\"uk-paragraph\">a</p>\n<ul class=\"uk-list uk-list-disc mt-4\">\n<li>===</li>\n</ul>",
+        "should not support piercing (1)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("a\n* ---", &Options::default())?.html,
+        "<p class=\"uk-paragraph\">a</p>\n<ul class=\"uk-list uk-list-disc mt-4\">\n<li>\n<hr class=\"uk-divider-icon mt-4\"/>\n</li>\n</ul>",
+        "should not support piercing (2)"
+    );
+
+    assert_eq!(
+        to_frankenui_document(
+            "a\n=\n\nb\n-",
+            &Options {
+                compile: CompileOptions {
+                    frankenui: FrankenUiOptions {
+                        heading_rule: false,
+                        ..FrankenUiOptions::default()
+                    },
+                    ..CompileOptions::default()
+                },
+                ..Options::default()
+            }
+        )?
+        .html,
+        "<h1 class=\"uk-h1 mt-8 mb-4\" id=\"a\">a</h1>\n<h2 class=\"uk-h2 mt-8 mb-4\" id=\"b\">b</h2>",
+        "should support turning off the rule after headings w/ rank 1 and 2"
+    );
+
+    assert_eq!(
+        to_frankenui_document(
+            "a\n-",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        heading_setext: false,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?
+        .html,
+        "<p class=\"uk-paragraph\">a\n-</p>",
+        "should support turning off setext underlines"
+    );
+
+    Ok(())
+}
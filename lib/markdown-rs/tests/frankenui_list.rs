@@ -0,0 +1,530 @@
+use markdown::{message, to_frankenui_document, CompileOptions, Constructs, Options, ParseOptions};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn frankenui_list() -> Result<(), message::Message> {
+    assert_eq!(
+        to_frankenui_document("1.  a\n    b.\n\n        c\n\n    > d.", &Options::default())?.html,
+        "<ol class=\"uk-list uk-list-decimal mt-4\">\n<li>\n<p class=\"uk-paragraph\">a\nb.</p>\n<pre class=\"mt-4\"><code>c\n</code></pre>\n<blockquote class=\"uk-blockquote mt-4\">\n<p class=\"uk-paragraph\">d.</p>\n</blockquote>\n</li>\n</ol>",
+        "should support documents in list items"
+    );
+
+    assert_eq!(
+        to_frankenui_document("- one\n\n two", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>one</li>\n</ul>\n<p class=\"uk-paragraph\">two</p>",
+        "should not support 1 space for a two-character list prefix"
+    );
+
+    assert_eq!(
+        to_frankenui_document("- a\n\n  b", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>\n<p class=\"uk-paragraph\">a</p>\n<p class=\"uk-paragraph\">b</p>\n</li>\n</ul>",
+        "should support blank lines in list items"
+    );
+
+    assert_eq!(
+        to_frankenui_document(" -    one\n\n     two", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>one</li>\n</ul>\n<pre class=\"mt-4\"><code> two\n</code></pre>",
+        "should support indented code after lists"
+    );
+
+    assert_eq!(
+        to_frankenui_document("   > > 1.  one\n>>\n>>     two", &Options::default())?.html,
+        "<blockquote class=\"uk-blockquote mt-4\">\n<blockquote class=\"uk-blockquote mt-4\">\n<ol class=\"uk-list uk-list-decimal mt-4\">\n<li>\n<p class=\"uk-paragraph\">one</p>\n<p class=\"uk-paragraph\">two</p>\n</li>\n</ol>\n</blockquote>\n</blockquote>",
+        "should support proper indent mixed w/ block quotes (1)"
+    );
+
+    assert_eq!(
+        to_frankenui_document(">>- one\n>>\n  >  > two", &Options::default())?.html,
+        "<blockquote class=\"uk-blockquote mt-4\">\n<blockquote class=\"uk-blockquote mt-4\">\n<ul class=\"uk-list uk-list-disc mt-4\">\n<li>one</li>\n</ul>\n<p class=\"uk-paragraph\">two</p>\n</blockquote>\n</blockquote>",
+        "should support proper indent mixed w/ block quotes (2)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("-one\n\n2.two", &Options::default())?.html,
+        "<p class=\"uk-paragraph\">-one</p>\n<p class=\"uk-paragraph\">2.two</p>",
+        "should not support a missing space after marker"
+    );
+
+    assert_eq!(
+        to_frankenui_document("- foo\n\n\n  bar", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>\n<p class=\"uk-paragraph\">foo</p>\n<p class=\"uk-paragraph\">bar</p>\n</li>\n</ul>",
+        "should support multiple blank lines between items"
+    );
+
+    assert_eq!(
+        to_frankenui_document("1.  foo\n\n    ```\n    bar\n    ```\n\n    baz\n\n    > bam", &Options::default())?.html,
+        "<ol class=\"uk-list uk-list-decimal mt-4\">\n<li>\n<p class=\"uk-paragraph\">foo</p>\n<pre class=\"mt-4\"><code>bar\n</code></pre>\n<p class=\"uk-paragraph\">baz</p>\n<blockquote class=\"uk-blockquote mt-4\">\n<p class=\"uk-paragraph\">bam</p>\n</blockquote>\n</li>\n</ol>",
+        "should support flow in items"
+    );
+
+    assert_eq!(
+        to_frankenui_document("- Foo\n\n      bar\n\n\n      baz", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>\n<p class=\"uk-paragraph\">Foo</p>\n<pre class=\"mt-4\"><code>bar\n\n\nbaz\n</code></pre>\n</li>\n</ul>",
+        "should support blank lines in indented code in items"
+    );
+
+    assert_eq!(
+        to_frankenui_document("123456789. ok", &Options::default())?.html,
+        "<ol class=\"uk-list uk-list-decimal mt-4\" start=\"123456789\">\n<li>ok</li>\n</ol>",
+        "should support start on the first list item"
+    );
+
+    assert_eq!(
+        to_frankenui_document("1234567890. not ok", &Options::default())?.html,
+        "<p class=\"uk-paragraph\">1234567890. not ok</p>",
+        "should not support ordered item values over 10 digits"
+    );
+
+    assert_eq!(
+        to_frankenui_document("0. ok", &Options::default())?.html,
+        "<ol class=\"uk-list uk-list-decimal mt-4\" start=\"0\">\n<li>ok</li>\n</ol>",
+        "should support ordered item values of `0`"
+    );
+
+    assert_eq!(
+        to_frankenui_document("003. ok", &Options::default())?.html,
+        "<ol class=\"uk-list uk-list-decimal mt-4\" start=\"3\">\n<li>ok</li>\n</ol>",
+        "should support ordered item values starting w/ `0`s"
+    );
+
+    assert_eq!(
+        to_frankenui_document("-1. not ok", &Options::default())?.html,
+        "<p class=\"uk-paragraph\">-1. not ok</p>",
+        "should not support “negative” ordered item values"
+    );
+
+    assert_eq!(
+        to_frankenui_document("- foo\n\n      bar", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>\n<p class=\"uk-paragraph\">foo</p>\n<pre class=\"mt-4\"><code>bar\n</code></pre>\n</li>\n</ul>",
+        "should support indented code in list items (1)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("  10.  foo\n\n           bar", &Options::default())?.html,
+        "<ol class=\"uk-list uk-list-decimal mt-4\" start=\"10\">\n<li>\n<p class=\"uk-paragraph\">foo</p>\n<pre class=\"mt-4\"><code>bar\n</code></pre>\n</li>\n</ol>",
+        "should support indented code in list items (2)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("    indented code\n\nparagraph\n\n    more code", &Options::default())?.html,
+        "<pre class=\"mt-4\"><code>indented code\n</code></pre>\n<p class=\"uk-paragraph\">paragraph</p>\n<pre class=\"mt-4\"><code>more code\n</code></pre>",
+        "should support indented code in list items (3)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("1.     indented code\n\n   paragraph\n\n       more code", &Options::default())?.html,
+        "<ol class=\"uk-list uk-list-decimal mt-4\">\n<li>\n<pre class=\"mt-4\"><code>indented code\n</code></pre>\n<p class=\"uk-paragraph\">paragraph</p>\n<pre class=\"mt-4\"><code>more code\n</code></pre>\n</li>\n</ol>",
+        "should support indented code in list items (4)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("1.      indented code\n\n   paragraph\n\n       more code", &Options::default())?.html,
+        "<ol class=\"uk-list uk-list-decimal mt-4\">\n<li>\n<pre class=\"mt-4\"><code> indented code\n</code></pre>\n<p class=\"uk-paragraph\">paragraph</p>\n<pre class=\"mt-4\"><code>more code\n</code></pre>\n</li>\n</ol>",
+        "should support indented code in list items (5)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("   foo\n\nbar", &Options::default())?.html,
+        "<p class=\"uk-paragraph\">foo</p>\n<p class=\"uk-paragraph\">bar</p>",
+        "should support indented code in list items (6)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("-    foo\n\n  bar", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>foo</li>\n</ul>\n<p class=\"uk-paragraph\">bar</p>",
+        "should support indented code in list items (7)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("-  foo\n\n   bar", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>\n<p class=\"uk-paragraph\">foo</p>\n<p class=\"uk-paragraph\">bar</p>\n</li>\n</ul>",
+        "should support indented code in list items (8)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("-\n  foo\n-\n  ```\n  bar\n  ```\n-\n      baz", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>foo</li>\n<li>\n<pre class=\"mt-4\"><code>bar\n</code></pre>\n</li>\n<li>\n<pre class=\"mt-4\"><code>baz\n</code></pre>\n</li>\n</ul>",
+        "should support blank first lines (1)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("-   \n  foo", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>foo</li>\n</ul>",
+        "should support blank first lines (2)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("-\n\n  foo", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li></li>\n</ul>\n<p class=\"uk-paragraph\">foo</p>",
+        "should support empty only items"
+    );
+
+    assert_eq!(
+        to_frankenui_document("- foo\n-\n- bar", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>foo</li>\n<li></li>\n<li>bar</li>\n</ul>",
+        "should support empty continued items"
+    );
+
+    assert_eq!(
+        to_frankenui_document("- foo\n-   \n- bar", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>foo</li>\n<li></li>\n<li>bar</li>\n</ul>",
+        "should support blank continued items"
+    );
+
+    assert_eq!(
+        to_frankenui_document("1. foo\n2.\n3. bar", &Options::default())?.html,
+        "<ol class=\"uk-list uk-list-decimal mt-4\">\n<li>foo</li>\n<li></li>\n<li>bar</li>\n</ol>",
+        "should support empty continued items (ordered)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("*", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li></li>\n</ul>",
+        "should support a single empty item"
+    );
+
+    assert_eq!(
+        to_frankenui_document("foo\n*\n\nfoo\n1.", &Options::default())?.html,
+        "<p class=\"uk-paragraph\">foo\n*</p>\n<p class=\"uk-paragraph\">foo\n1.</p>",
+        "should not support empty items to interrupt paragraphs"
+    );
+
+    assert_eq!(
+        to_frankenui_document(
+            "  1.  A paragraph\n    with two lines.",
+            &Options::default()
+        )?
+        .html,
+        "<ol class=\"uk-list uk-list-decimal mt-4\">\n<li>A paragraph\nwith two lines.</li>\n</ol>",
+        "should support partially lazy lines"
+    );
+
+    assert_eq!(
+        to_frankenui_document("> 1. > Blockquote\ncontinued here.", &Options::default())?.html,
+        "<blockquote class=\"uk-blockquote mt-4\">\n<ol class=\"uk-list uk-list-decimal mt-4\">\n<li>\n<blockquote class=\"uk-blockquote mt-4\">\n<p class=\"uk-paragraph\">Blockquote\ncontinued here.</p>\n</blockquote>\n</li>\n</ol>\n</blockquote>",
+        "should support lazy lines combined w/ other containers"
+    );
+
+    assert_eq!(
+        to_frankenui_document("> 1. > Blockquote\n> continued here.", &Options::default())?.html,
+        "<blockquote class=\"uk-blockquote mt-4\">\n<ol class=\"uk-list uk-list-decimal mt-4\">\n<li>\n<blockquote class=\"uk-blockquote mt-4\">\n<p class=\"uk-paragraph\">Blockquote\ncontinued here.</p>\n</blockquote>\n</li>\n</ol>\n</blockquote>",
+        "should support partially continued, partially lazy lines combined w/ other containers"
+    );
+
+    assert_eq!(
+        to_frankenui_document("- [\na", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>[\na</li>\n</ul>",
+        "should support lazy, definition-like lines"
+    );
+
+    assert_eq!(
+        to_frankenui_document("- [a]: b\nc", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>c</li>\n</ul>",
+        "should support a definition, followed by a lazy paragraph"
+    );
+
+    assert_eq!(
+        to_frankenui_document("- foo\n  - bar\n    - baz\n      - boo", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>foo\n<ul class=\"uk-list uk-list-disc mt-4\">\n<li>bar\n<ul class=\"uk-list uk-list-disc mt-4\">\n<li>baz\n<ul class=\"uk-list uk-list-disc mt-4\">\n<li>boo</li>\n</ul>\n</li>\n</ul>\n</li>\n</ul>\n</li>\n</ul>",
+        "should support sublists w/ enough spaces (1)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("- foo\n - bar\n  - baz\n   - boo", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>foo</li>\n<li>bar</li>\n<li>baz</li>\n<li>boo</li>\n</ul>",
+        "should not support sublists w/ too few spaces"
+    );
+
+    assert_eq!(
+        to_frankenui_document("10) foo\n    - bar", &Options::default())?.html,
+        "<ol class=\"uk-list uk-list-decimal mt-4\" start=\"10\">\n<li>foo\n<ul class=\"uk-list uk-list-disc mt-4\">\n<li>bar</li>\n</ul>\n</li>\n</ol>",
+        "should support sublists w/ enough spaces (2)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("10) foo\n   - bar", &Options::default())?.html,
+        "<ol class=\"uk-list uk-list-decimal mt-4\" start=\"10\">\n<li>foo</li>\n</ol>\n<ul class=\"uk-list uk-list-disc mt-4\">\n<li>bar</li>\n</ul>",
+        "should not support sublists w/ too few spaces (2)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("- - foo", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>\n<ul class=\"uk-list uk-list-disc mt-4\">\n<li>foo</li>\n</ul>\n</li>\n</ul>",
+        "should support sublists (1)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("1. - 2. foo", &Options::default())?.html,
+        "<ol class=\"uk-list uk-list-decimal mt-4\">\n<li>\n<ul class=\"uk-list uk-list-disc mt-4\">\n<li>\n<ol class=\"uk-list uk-list-decimal mt-4\" start=\"2\">\n<li>foo</li>\n</ol>\n</li>\n</ul>\n</li>\n</ol>",
+        "should support sublists (2)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("- # Foo\n- Bar\n  ---\n  baz", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>\n<h1 class=\"uk-h1 mt-8 mb-4\" id=\"foo\">Foo</h1>\n<hr class=\"uk-hr mb-6\"/>\n</li>\n<li>\n<h2 class=\"uk-h2 mt-8 mb-4\" id=\"bar\">Bar</h2>\n<hr class=\"uk-hr mb-6\"/>\nbaz</li>\n</ul>",
+        "should support headings in list items"
+    );
+
+    assert_eq!(
+        to_frankenui_document("- foo\n- bar\n+ baz", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>foo</li>\n<li>bar</li>\n</ul>\n<ul class=\"uk-list uk-list-disc mt-4\">\n<li>baz</li>\n</ul>",
+        "should support a new list by changing the marker (unordered)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("1. foo\n2. bar\n3) baz", &Options::default())?.html,
+        "<ol class=\"uk-list uk-list-decimal mt-4\">\n<li>foo</li>\n<li>bar</li>\n</ol>\n<ol class=\"uk-list uk-list-decimal mt-4\" start=\"3\">\n<li>baz</li>\n</ol>",
+        "should support a new list by changing the marker (ordered)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("Foo\n- bar\n- baz", &Options::default())?.html,
+        "<p class=\"uk-paragraph\">Foo</p>\n<ul class=\"uk-list uk-list-disc mt-4\">\n<li>bar</li>\n<li>baz</li>\n</ul>",
+        "should support interrupting a paragraph"
+    );
+
+    assert_eq!(
+        to_frankenui_document("a\n2. b", &Options::default())?.html,
+        "<p class=\"uk-paragraph\">a\n2. b</p>",
+        "should not support interrupting a paragraph with a non-1 numbered item"
+    );
+
+    assert_eq!(
+        to_frankenui_document("\n2. a", &Options::default())?.html,
+        "<ol class=\"uk-list uk-list-decimal mt-4\" start=\"2\">\n<li>a</li>\n</ol>",
+        "should “interrupt” a blank line (1)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("a\n\n2. b", &Options::default())?.html,
+        "<p class=\"uk-paragraph\">a</p>\n<ol class=\"uk-list uk-list-decimal mt-4\" start=\"2\">\n<li>b</li>\n</ol>",
+        "should “interrupt” a blank line (2)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("a\n1. b", &Options::default())?.html,
+        "<p class=\"uk-paragraph\">a</p>\n<ol class=\"uk-list uk-list-decimal mt-4\">\n<li>b</li>\n</ol>",
+        "should support interrupting a paragraph with a 1 numbered item"
+    );
+
+    assert_eq!(
+        to_frankenui_document("- foo\n\n- bar\n\n\n- baz", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>\n<p class=\"uk-paragraph\">foo</p>\n</li>\n<li>\n<p class=\"uk-paragraph\">bar</p>\n</li>\n<li>\n<p class=\"uk-paragraph\">baz</p>\n</li>\n</ul>",
+        "should support blank lines between items (1)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("- foo\n  - bar\n    - baz\n\n\n      bim", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>foo\n<ul class=\"uk-list uk-list-disc mt-4\">\n<li>bar\n<ul class=\"uk-list uk-list-disc mt-4\">\n<li>\n<p class=\"uk-paragraph\">baz</p>\n<p class=\"uk-paragraph\">bim</p>\n</li>\n</ul>\n</li>\n</ul>\n</li>\n</ul>",
+        "should support blank lines between items (2)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("- a\n - b\n  - c\n   - d\n  - e\n - f\n- g", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>a</li>\n<li>b</li>\n<li>c</li>\n<li>d</li>\n<li>e</li>\n<li>f</li>\n<li>g</li>\n</ul>",
+        "should not support lists in lists w/ too few spaces (1)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("1. a\n\n  2. b\n\n   3. c", &Options::default())?.html,
+        "<ol class=\"uk-list uk-list-decimal mt-4\">\n<li>\n<p class=\"uk-paragraph\">a</p>\n</li>\n<li>\n<p class=\"uk-paragraph\">b</p>\n</li>\n<li>\n<p class=\"uk-paragraph\">c</p>\n</li>\n</ol>",
+        "should not support lists in lists w/ too few spaces (2)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("- a\n - b\n  - c\n   - d\n    - e", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>a</li>\n<li>b</li>\n<li>c</li>\n<li>d\n- e</li>\n</ul>",
+        "should not support lists in lists w/ too few spaces (3)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("1. a\n\n  2. b\n\n    3. c", &Options::default())?.html,
+        "<ol class=\"uk-list uk-list-decimal mt-4\">\n<li>\n<p class=\"uk-paragraph\">a</p>\n</li>\n<li>\n<p class=\"uk-paragraph\">b</p>\n</li>\n</ol>\n<pre class=\"mt-4\"><code>3. c\n</code></pre>",
+        "should not support lists in lists w/ too few spaces (3)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("- a\n- b\n\n- c", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>\n<p class=\"uk-paragraph\">a</p>\n</li>\n<li>\n<p class=\"uk-paragraph\">b</p>\n</li>\n<li>\n<p class=\"uk-paragraph\">c</p>\n</li>\n</ul>",
+        "should support loose lists w/ a blank line between (1)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("* a\n*\n\n* c", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>\n<p class=\"uk-paragraph\">a</p>\n</li>\n<li></li>\n<li>\n<p class=\"uk-paragraph\">c</p>\n</li>\n</ul>",
+        "should support loose lists w/ a blank line between (2)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("- a\n- b\n\n  c\n- d", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>\n<p class=\"uk-paragraph\">a</p>\n</li>\n<li>\n<p class=\"uk-paragraph\">b</p>\n<p class=\"uk-paragraph\">c</p>\n</li>\n<li>\n<p class=\"uk-paragraph\">d</p>\n</li>\n</ul>",
+        "should support loose lists w/ a blank line in an item (1)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("- a\n- b\n\n  [ref]: /url\n- d", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>\n<p class=\"uk-paragraph\">a</p>\n</li>\n<li>\n<p class=\"uk-paragraph\">b</p>\n</li>\n<li>\n<p class=\"uk-paragraph\">d</p>\n</li>\n</ul>",
+        "should support loose lists w/ a blank line in an item (2)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("- a\n- ```\n  b\n\n\n  ```\n- c", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>a</li>\n<li>\n<pre class=\"mt-4\"><code>b\n\n\n</code></pre>\n</li>\n<li>c</li>\n</ul>",
+        "should support tight lists w/ a blank line in fenced code"
+    );
+
+    assert_eq!(
+        to_frankenui_document("- a\n  - b\n\n    c\n- d", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>a\n<ul class=\"uk-list uk-list-disc mt-4\">\n<li>\n<p class=\"uk-paragraph\">b</p>\n<p class=\"uk-paragraph\">c</p>\n</li>\n</ul>\n</li>\n<li>d</li>\n</ul>",
+        "should support tight lists w/ a blank line in a sublist"
+    );
+
+    assert_eq!(
+        to_frankenui_document("* a\n  > b\n  >\n* c", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>a\n<blockquote class=\"uk-blockquote mt-4\">\n<p class=\"uk-paragraph\">b</p>\n</blockquote>\n</li>\n<li>c</li>\n</ul>",
+        "should support tight lists w/ a blank line in a block quote"
+    );
+
+    assert_eq!(
+        to_frankenui_document("- a\n  > b\n  ```\n  c\n  ```\n- d", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>a\n<blockquote class=\"uk-blockquote mt-4\">\n<p class=\"uk-paragraph\">b</p>\n</blockquote>\n<pre class=\"mt-4\"><code>c\n</code></pre>\n</li>\n<li>d</li>\n</ul>",
+        "should support tight lists w/ flow w/o blank line"
+    );
+
+    assert_eq!(
+        to_frankenui_document("- a", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>a</li>\n</ul>",
+        "should support tight lists w/ a single content"
+    );
+
+    assert_eq!(
+        to_frankenui_document("- a\n  - b", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>a\n<ul class=\"uk-list uk-list-disc mt-4\">\n<li>b</li>\n</ul>\n</li>\n</ul>",
+        "should support tight lists w/ a sublist"
+    );
+
+    assert_eq!(
+        to_frankenui_document("1. ```\n   foo\n   ```\n\n   bar", &Options::default())?.html,
+        "<ol class=\"uk-list uk-list-decimal mt-4\">\n<li>\n<pre class=\"mt-4\"><code>foo\n</code></pre>\n<p class=\"uk-paragraph\">bar</p>\n</li>\n</ol>",
+        "should support loose lists w/ a blank line in an item"
+    );
+
+    assert_eq!(
+        to_frankenui_document("* foo\n  * bar\n\n  baz", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>\n<p class=\"uk-paragraph\">foo</p>\n<ul class=\"uk-list uk-list-disc mt-4\">\n<li>bar</li>\n</ul>\n<p class=\"uk-paragraph\">baz</p>\n</li>\n</ul>",
+        "should support loose lists w/ tight sublists (1)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("- a\n  - b\n  - c\n\n- d\n  - e\n  - f", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>\n<p class=\"uk-paragraph\">a</p>\n<ul class=\"uk-list uk-list-disc mt-4\">\n<li>b</li>\n<li>c</li>\n</ul>\n</li>\n<li>\n<p class=\"uk-paragraph\">d</p>\n<ul class=\"uk-list uk-list-disc mt-4\">\n<li>e</li>\n<li>f</li>\n</ul>\n</li>\n</ul>",
+        "should support loose lists w/ tight sublists (2)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("* a\n*\n\n  \n\t\n* b", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>\n<p class=\"uk-paragraph\">a</p>\n</li>\n<li></li>\n<li>\n<p class=\"uk-paragraph\">b</p>\n</li>\n</ul>",
+        "should support continued list items after an empty list item w/ many blank lines"
+    );
+
+    assert_eq!(
+        to_frankenui_document("*\n  ~~~p\n\n  ~~~", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>\n<pre class=\"mt-4\"><code class=\"language-p\">\n</code></pre>\n</li>\n</ul>",
+        "should support blank lines in code after an initial blank line"
+    );
+
+    assert_eq!(
+        to_frankenui_document("*   foo\n\n*\n\n*   bar", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>\n<p class=\"uk-paragraph\">foo</p>\n</li>\n<li></li>\n<li>\n<p class=\"uk-paragraph\">bar</p>\n</li>\n</ul>",
+        "should support empty items in a spread list"
+    );
+
+    assert_eq!(
+        to_frankenui_document("- ```\n\n  ```", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>\n<pre class=\"mt-4\"><code>\n</code></pre>\n</li>\n</ul>",
+        "should remove indent of code (fenced) in list (0 space)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("- ```\n \n  ```", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>\n<pre class=\"mt-4\"><code>\n</code></pre>\n</li>\n</ul>",
+        "should remove indent of code (fenced) in list (1 space)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("- ```\n  \n  ```", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>\n<pre class=\"mt-4\"><code>\n</code></pre>\n</li>\n</ul>",
+        "should remove indent of code (fenced) in list (2 spaces)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("- ```\n   \n  ```", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>\n<pre class=\"mt-4\"><code> \n</code></pre>\n</li>\n</ul>",
+        "should remove indent of code (fenced) in list (3 spaces)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("- ```\n    \n  ```", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>\n<pre class=\"mt-4\"><code>  \n</code></pre>\n</li>\n</ul>",
+        "should remove indent of code (fenced) in list (4 spaces)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("- ```\n\t\n  ```", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>\n<pre class=\"mt-4\"><code>  \n</code></pre>\n</li>\n</ul>",
+        "should remove indent of code (fenced) in list (1 tab)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("- +\n-", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>\n<ul class=\"uk-list uk-list-disc mt-4\">\n<li></li>\n</ul>\n</li>\n<li></li>\n</ul>",
+        "should support complex nested and empty lists (1)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("- 1.\n-", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>\n<ol class=\"uk-list uk-list-decimal mt-4\">\n<li></li>\n</ol>\n</li>\n<li></li>\n</ul>",
+        "should support complex nested and empty lists (2)"
+    );
+
+    assert_eq!(
+        to_frankenui_document("* - +\n* -", &Options::default())?.html,
+        "<ul class=\"uk-list uk-list-disc mt-4\">\n<li>\n<ul class=\"uk-list uk-list-disc mt-4\">\n<li>\n<ul class=\"uk-list uk-list-disc mt-4\">\n<li></li>\n</ul>\n</li>\n</ul>\n</li>\n<li>\n<ul class=\"uk-list uk-list-disc mt-4\">\n<li></li>\n</ul>\n</li>\n</ul>",
+        "should support complex nested and empty lists (3)"
+    );
+
+    let danger = Options {
+        compile: CompileOptions {
+            allow_dangerous_html: true,
+            ..CompileOptions::default()
+        },
+        ..Options::default()
+    };
+
+    assert_eq!(
+        to_frankenui_document("1. a\n2. b\n\n<!---->\n\n5. c", &danger)?.html,
+        "<ol class=\"uk-list uk-list-decimal mt-4\">\n<li>a</li>\n<li>b</li>\n</ol>\n<!---->\n<ol class=\"uk-list uk-list-decimal mt-4\" start=\"5\">\n<li>c</li>\n</ol>",
+        "should support the common list breaking comment method w/ ordered lists"
+    );
+
+    assert_eq!(
+        to_frankenui_document(
+            "- one\n\n two",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        list_item: false,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?
+        .html,
+        "<p class=\"uk-paragraph\">- one</p>\n<p class=\"uk-paragraph\">two</p>",
+        "should support turning off lists"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,211 @@
+use markdown::{
+    mdast::{Heading, Node, Root, Text},
+    message, to_frankenui_document, to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    Constructs, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn heading_attributes() -> Result<(), message::Message> {
+    let attributes = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                heading_attributes: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("## a {#b}"),
+        "<h2>a {#b}</h2>",
+        "should not support heading attributes by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("## a {#b}", &attributes)?,
+        "<h2>a</h2>",
+        "should support an id"
+    );
+
+    assert_eq!(
+        to_html_with_options("## a {.b .c}", &attributes)?,
+        "<h2>a</h2>",
+        "should support classes"
+    );
+
+    assert_eq!(
+        to_html_with_options("## a { #b\t.c }\t ", &attributes)?,
+        "<h2>a</h2>",
+        "should support whitespace in and after attributes"
+    );
+
+    assert_eq!(
+        to_html_with_options("## {#b}", &attributes)?,
+        "<h2></h2>",
+        "should support attributes w/o text"
+    );
+
+    assert_eq!(
+        to_html_with_options("## a ## {#b}", &attributes)?,
+        "<h2>a</h2>",
+        "should support attributes after a closing sequence"
+    );
+
+    assert_eq!(
+        to_html_with_options("## a {#b} ##", &attributes)?,
+        "<h2>a {#b}</h2>",
+        "should not support attributes before a closing sequence"
+    );
+
+    assert_eq!(
+        to_html_with_options("## a {#b} c", &attributes)?,
+        "<h2>a {#b} c</h2>",
+        "should not support attributes followed by text"
+    );
+
+    assert_eq!(
+        to_html_with_options("## a {#b} {#c}", &attributes)?,
+        "<h2>a {#b}</h2>",
+        "should only support the last attributes"
+    );
+
+    assert_eq!(
+        to_html_with_options("## a{#b}", &attributes)?,
+        "<h2>a{#b}</h2>",
+        "should not support attributes w/o whitespace before them"
+    );
+
+    assert_eq!(
+        to_html_with_options("## a {}", &attributes)?,
+        "<h2>a {}</h2>",
+        "should not support empty attributes"
+    );
+
+    assert_eq!(
+        to_html_with_options("## a {b}", &attributes)?,
+        "<h2>a {b}</h2>",
+        "should not support attributes w/o `#` or `.`"
+    );
+
+    assert_eq!(
+        to_html_with_options("## a {#}", &attributes)?,
+        "<h2>a {#}</h2>",
+        "should not support attributes w/o name"
+    );
+
+    assert_eq!(
+        to_html_with_options("## a {#b!}", &attributes)?,
+        "<h2>a {#b!}</h2>",
+        "should not support punctuation other than `-`, `_`, and `:` in names"
+    );
+
+    assert_eq!(
+        to_html_with_options("## a {#b", &attributes)?,
+        "<h2>a {#b</h2>",
+        "should not support attributes w/o closing brace"
+    );
+
+    assert_eq!(
+        to_html_with_options("## a \\{#b}", &attributes)?,
+        "<h2>a {#b}</h2>",
+        "should not support an escaped opening brace"
+    );
+
+    assert_eq!(
+        to_html_with_options("## a {#b}\nc", &attributes)?,
+        "<h2>a</h2>\n<p>c</p>",
+        "should support attributes before a line ending"
+    );
+
+    assert_eq!(
+        to_html_with_options("a {#b}\n=", &attributes)?,
+        "<h1>a {#b}</h1>",
+        "should not support attributes on heading (setext)"
+    );
+
+    assert_eq!(
+        to_mdast("## a {#b .c .d}", &attributes.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Heading(Heading {
+                children: vec![Node::Text(Text {
+                    value: "a".into(),
+                    position: Some(Position::new(1, 4, 3, 1, 5, 4))
+                })],
+                position: Some(Position::new(1, 1, 0, 1, 16, 15)),
+                depth: 2,
+                id: Some("b".into()),
+                classes: vec!["c".into(), "d".into()]
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 16, 15))
+        }),
+        "should support attributes as `id` and `classes` on `Heading`s in mdast"
+    );
+
+    assert_eq!(
+        to_mdast("## a {#b #c}", &attributes.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Heading(Heading {
+                children: vec![Node::Text(Text {
+                    value: "a".into(),
+                    position: Some(Position::new(1, 4, 3, 1, 5, 4))
+                })],
+                position: Some(Position::new(1, 1, 0, 1, 13, 12)),
+                depth: 2,
+                id: Some("c".into()),
+                classes: vec![]
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 13, 12))
+        }),
+        "should use the last id in mdast"
+    );
+
+    assert_eq!(
+        to_frankenui_document("a {#b .c}\n-", &attributes)?.html,
+        "<h2 class=\"uk-h2 mt-8 mb-4\" id=\"a-b-c\">a {#b .c}</h2>\n<hr class=\"uk-hr mb-6\"/>",
+        "should leave attributes on heading (setext) in `FrankenUI` HTML"
+    );
+
+    assert_eq!(
+        to_frankenui_document("## a *b* {#c .d}", &attributes)?.html,
+        "<h2 class=\"uk-h2 mt-8 mb-4 d\" id=\"c\">a <em class=\"italic\">b</em></h2>\n<hr class=\"uk-hr mb-6\"/>",
+        "should write attributes in `FrankenUI` HTML"
+    );
+
+    let ids = |value: &str| -> Result<Vec<String>, message::Message> {
+        Ok(to_frankenui_document(value, &attributes)?
+            .outline
+            .into_iter()
+            .map(|heading| heading.id)
+            .collect())
+    };
+
+    assert_eq!(
+        ids("# Install {#install-linux}\n\n## {#empty}")?,
+        vec!["install-linux", "empty"],
+        "should use explicit ids in the outline"
+    );
+
+    assert_eq!(
+        ids("# a {#b}\n\n# b")?,
+        vec!["b", "b-1"],
+        "should not give slugs the explicit ids that are taken"
+    );
+
+    assert_eq!(
+        ids("# a\n\n# b {#a}")?,
+        vec!["a", "a-1"],
+        "should suffix explicit ids that are taken by slugs"
+    );
+
+    assert_eq!(
+        ids("# a {#b}\n\n# c {#b}\n\n# d {#b}")?,
+        vec!["b", "b-1", "b-2"],
+        "should suffix explicit ids that are taken by explicit ids"
+    );
+
+    Ok(())
+}
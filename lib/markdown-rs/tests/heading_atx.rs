@@ -229,6 +229,8 @@ fn heading_atx() -> Result<(), message::Message> {
         Node::Root(Root {
             children: vec![Node::Heading(Heading {
                 depth: 2,
+                id: None,
+                classes: vec![],
                 children: vec![Node::Text(Text {
                     value: "alpha".into(),
                     position: Some(Position::new(1, 4, 3, 1, 9, 8))
@@ -302,6 +302,8 @@ fn heading_setext() -> Result<(), message::Message> {
         Node::Root(Root {
             children: vec![Node::Heading(Heading {
                 depth: 1,
+                id: None,
+                classes: vec![],
                 children: vec![Node::Text(Text {
                     value: "alpha\nbravo".into(),
                     position: Some(Position::new(1, 1, 0, 2, 6, 11))
@@ -16,7 +16,7 @@ fn serde_constructs() -> Result<(), Error> {
 
     assert_eq!(
         serde_json::to_string(&Constructs::default()).unwrap(),
-        r#"{"attention":true,"autolink":true,"blockQuote":true,"characterEscape":true,"characterReference":true,"codeIndented":true,"codeFenced":true,"codeText":true,"definition":true,"frontmatter":false,"gfmAutolinkLiteral":false,"gfmFootnoteDefinition":false,"gfmLabelStartFootnote":false,"gfmStrikethrough":false,"gfmTable":false,"gfmTaskListItem":false,"hardBreakEscape":true,"hardBreakTrailing":true,"headingAtx":true,"headingSetext":true,"htmlFlow":true,"htmlText":true,"labelStartImage":true,"labelStartLink":true,"labelEnd":true,"listItem":true,"mathFlow":false,"mathText":false,"mdxEsm":false,"mdxExpressionFlow":false,"mdxExpressionText":false,"mdxJsxFlow":false,"mdxJsxText":false,"thematicBreak":true}"#
+        r#"{"attention":true,"autolink":true,"blockQuote":true,"characterEscape":true,"characterReference":true,"codeIndented":true,"codeFenced":true,"codeText":true,"definition":true,"frontmatter":false,"gfmAutolinkLiteral":false,"gfmFootnoteDefinition":false,"gfmLabelStartFootnote":false,"gfmStrikethrough":false,"gfmTable":false,"gfmTaskListItem":false,"hardBreakEscape":true,"hardBreakTrailing":true,"headingAttributes":false,"headingAtx":true,"headingSetext":true,"htmlFlow":true,"htmlText":true,"labelStartImage":true,"labelStartLink":true,"labelEnd":true,"listItem":true,"mathFlow":false,"mathText":false,"mdxEsm":false,"mdxExpressionFlow":false,"mdxExpressionText":false,"mdxJsxFlow":false,"mdxJsxText":false,"thematicBreak":true}"#
     );
 
     Ok(())
@@ -29,7 +29,7 @@ fn serde_compile_options() -> Result<(), Error> {
 
     assert_eq!(
         serde_json::to_string(&markdown::CompileOptions::gfm()).unwrap(),
-        r#"{"allowAnyImgSrc":false,"allowDangerousHtml":false,"allowDangerousProtocol":false,"defaultLineEnding":"\n","gfmFootnoteBackLabel":null,"gfmFootnoteClobberPrefix":null,"gfmFootnoteLabelAttributes":null,"gfmFootnoteLabelTagName":null,"gfmFootnoteLabel":null,"gfmTaskListItemCheckable":false,"gfmTagfilter":true}"#
+        r#"{"allowAnyImgSrc":false,"allowDangerousHtml":false,"allowDangerousProtocol":false,"defaultLineEnding":"\n","gfmFootnoteBackLabel":null,"gfmFootnoteClobberPrefix":null,"gfmFootnoteLabelAttributes":null,"gfmFootnoteLabelTagName":null,"gfmFootnoteLabel":null,"gfmTaskListItemCheckable":false,"gfmTagfilter":true,"frankenui":{"codeLanguageBadge":false,"codeCopyButton":false,"headingRule":true,"classes":{"paragraph":"uk-paragraph","heading1":"uk-h1 mt-8 mb-4","heading2":"uk-h2 mt-8 mb-4","heading3":"uk-h3 mt-8 mb-4","heading4":"uk-h4 mt-8 mb-4","heading5":"uk-h5 mt-8 mb-4","heading6":"uk-h6 mt-8 mb-4","headingRule":"uk-hr mb-6","thematicBreak":"uk-divider-icon mt-4","blockQuote":"uk-blockquote mt-4","listOrdered":"uk-list uk-list-decimal mt-4","listUnordered":"uk-list uk-list-disc mt-4","taskListItemCheck":"uk-checkbox","emphasis":"italic","strong":"font-bold","strikethrough":"line-through","codeText":"uk-codespan","link":"uk-link","tableWrapper":"uk-overflow-auto mt-4","table":"uk-table uk-table-divider","tableCellLeft":"uk-text-left","tableCellRight":"uk-text-right","tableCellCenter":"uk-text-center","codeBlock":"mt-4","codeBlockInner":"m-0","codeBlockCard":"uk-card","codeBlockTitle":"uk-card-header px-4 py-2 font-mono text-sm","codeBlockToolbar":"absolute right-2 top-2 flex items-center gap-2","codeBlockBadge":"uk-badge uk-badge-secondary","codeBlockCopyButton":"uk-btn uk-btn-default uk-btn-xs uk-btn-icon","codeLine":"code-line","codeLineHighlighted":"code-line code-line-highlighted","codeLineNumber":"code-line-number"}}}"#
     );
 
     Ok(())
@@ -42,7 +42,7 @@ fn serde_parse_options() -> Result<(), Error> {
 
     assert_eq!(
         serde_json::to_string(&ParseOptions::gfm()).unwrap(),
-        r#"{"constructs":{"attention":true,"autolink":true,"blockQuote":true,"characterEscape":true,"characterReference":true,"codeIndented":true,"codeFenced":true,"codeText":true,"definition":true,"frontmatter":false,"gfmAutolinkLiteral":true,"gfmFootnoteDefinition":true,"gfmLabelStartFootnote":true,"gfmStrikethrough":true,"gfmTable":true,"gfmTaskListItem":true,"hardBreakEscape":true,"hardBreakTrailing":true,"headingAtx":true,"headingSetext":true,"htmlFlow":true,"htmlText":true,"labelStartImage":true,"labelStartLink":true,"labelEnd":true,"listItem":true,"mathFlow":false,"mathText":false,"mdxEsm":false,"mdxExpressionFlow":false,"mdxExpressionText":false,"mdxJsxFlow":false,"mdxJsxText":false,"thematicBreak":true},"gfmStrikethroughSingleTilde":true,"mathTextSingleDollar":true}"#
+        r#"{"constructs":{"attention":true,"autolink":true,"blockQuote":true,"characterEscape":true,"characterReference":true,"codeIndented":true,"codeFenced":true,"codeText":true,"definition":true,"frontmatter":false,"gfmAutolinkLiteral":true,"gfmFootnoteDefinition":true,"gfmLabelStartFootnote":true,"gfmStrikethrough":true,"gfmTable":true,"gfmTaskListItem":true,"hardBreakEscape":true,"hardBreakTrailing":true,"headingAttributes":false,"headingAtx":true,"headingSetext":true,"htmlFlow":true,"htmlText":true,"labelStartImage":true,"labelStartLink":true,"labelEnd":true,"listItem":true,"mathFlow":false,"mathText":false,"mdxEsm":false,"mdxExpressionFlow":false,"mdxExpressionText":false,"mdxJsxFlow":false,"mdxJsxText":false,"thematicBreak":true},"gfmStrikethroughSingleTilde":true,"mathTextSingleDollar":true}"#
     );
 
     Ok(())
@@ -0,0 +1,108 @@
+use std::{
+    collections::HashSet,
+    fs, io,
+    path::{Component, Path, PathBuf},
+};
+
+use crate::error::FrankmarkResult;
+use crate::models::Page;
+use crate::utils;
+
+// The file a local link of a page points at, and where it is copied to: the
+// same place relative to the output directory as it has relative to the
+// source directory. `None` for external links, pages, missing files and files
+// outside of the source directory.
+pub fn local_asset(
+    page_source: &Path,
+    link: &str,
+    source_dir: &Path,
+    output_dir: &Path,
+) -> Option<(PathBuf, PathBuf)> {
+    let target = utils::local_link_path(link)?;
+    let source = utils::normalize_path(&page_source.parent()?.join(target));
+    let is_markdown = source
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("md"));
+    if is_markdown || !source.is_file() {
+        return None;
+    }
+
+    let relative = source
+        .strip_prefix(utils::normalize_path(source_dir))
+        .ok()?;
+    if !relative
+        .components()
+        .all(|component| matches!(component, Component::Normal(_)))
+    {
+        return None;
+    }
+    let output = output_dir.join(relative);
+    Some((source, output))
+}
+
+// Copy every local file the pages link to, returning how many were copied.
+// Where each file goes is added to `outputs`, whether it was copied or was
+// already up to date.
+pub fn copy_page_assets<'a>(
+    pages: impl IntoIterator<Item = &'a Page>,
+    source_dir: &Path,
+    output_dir: &Path,
+    outputs: &mut HashSet<PathBuf>,
+) -> FrankmarkResult<usize> {
+    let mut copied = 0;
+    for page in pages {
+        for link in &page.links {
+            let Some((source, output)) =
+                local_asset(&page.source_path, &link.url, source_dir, output_dir)
+            else {
+                continue;
+            };
+            if copy_if_changed(&source, &output)? {
+                copied += 1;
+            }
+            outputs.insert(output);
+        }
+    }
+    Ok(copied)
+}
+
+// Copy a directory verbatim, returning how many files were copied and adding
+// where each file goes to `outputs`
+pub fn copy_dir(from: &Path, to: &Path, outputs: &mut HashSet<PathBuf>) -> FrankmarkResult<usize> {
+    let mut copied = 0;
+    for entry in fs::read_dir(from)? {
+        let path = entry?.path();
+        let Some(file_name) = path.file_name() else {
+            continue;
+        };
+        let target = to.join(file_name);
+
+        if path.is_dir() {
+            copied += copy_dir(&path, &target, outputs)?;
+        } else {
+            if copy_if_changed(&path, &target)? {
+                copied += 1;
+            }
+            outputs.insert(target);
+        }
+    }
+    Ok(copied)
+}
+
+// Skip files that are already up to date, so rebuilds in watch mode do not
+// copy every asset again
+fn copy_if_changed(from: &Path, to: &Path) -> io::Result<bool> {
+    let source = fs::metadata(from)?;
+    if let Ok(target) = fs::metadata(to)
+        && target.len() == source.len()
+        && target.modified()? >= source.modified()?
+    {
+        return Ok(false);
+    }
+
+    if let Some(parent) = to.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::copy(from, to)?;
+    Ok(true)
+}
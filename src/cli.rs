@@ -0,0 +1,126 @@
+use std::{num::NonZeroUsize, path::PathBuf, thread};
+
+use crate::error::{FrankmarkError, FrankmarkResult};
+
+pub const USAGE: &str = "\
+Usage: frankmark <command> [options]
+
+Commands:
+  build [dir]    Generate the site from <dir> (default: current directory)
+  check [dir]    Parse every page and validate links without writing output
+  clean [dir]    Remove the generated output directory
+  serve [dir]    Build, watch and preview the site on localhost with live reload
+  init [dir]     Scaffold a frankmark.toml and starter pages in <dir>
+  help           Print this message
+
+Options:
+  -o, --out <path>       Output directory (default: <dir>/output)
+  -c, --config <file>    Configuration file (default: <dir>/frankmark.toml)
+  -w, --watch            Rebuild whenever a source file or the configuration changes (build)
+  -p, --port <port>      Port for the preview server (serve, default: 3000)
+  -j, --jobs <n>         Pages to parse and render at once (build, check, serve,
+                         default: number of CPUs)
+  -s, --strict           Fail on broken links instead of warning (build, serve)
+      --deny-warnings    Fail on any warning, such as a missing page (build, check, serve)
+";
+
+#[derive(Debug)]
+pub enum Command {
+    Build(SiteArgs),
+    Check(SiteArgs),
+    Clean(SiteArgs),
+    Init(SiteArgs),
+    Serve(SiteArgs),
+    Help,
+}
+
+// Paths shared by every command, resolved against the source directory
+#[derive(Debug)]
+pub struct SiteArgs {
+    pub source_dir: PathBuf,
+    pub output_dir: PathBuf,
+    pub config_path: PathBuf,
+    pub watch: bool,
+    pub port: u16,
+    pub jobs: usize,
+    pub strict: bool,        // Broken links fail the build
+    pub deny_warnings: bool, // Any warning fails the build
+}
+
+pub fn parse_args(args: &[String]) -> FrankmarkResult<Command> {
+    let Some(command) = args.first() else {
+        return Ok(Command::Help);
+    };
+
+    let make_command: fn(SiteArgs) -> Command = match command.as_str() {
+        "build" => Command::Build,
+        "check" => Command::Check,
+        "clean" => Command::Clean,
+        "init" => Command::Init,
+        "serve" => Command::Serve,
+        "help" | "-h" | "--help" => return Ok(Command::Help),
+        other => return Err(usage_error(format!("unknown command '{}'", other))),
+    };
+
+    let mut source_dir: Option<PathBuf> = None;
+    let mut output_dir: Option<PathBuf> = None;
+    let mut config_path: Option<PathBuf> = None;
+    let mut watch = false;
+    let mut strict = false;
+    let mut deny_warnings = false;
+    let mut port = 3000;
+    let mut jobs = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+
+    let mut rest = args[1..].iter();
+    while let Some(arg) = rest.next() {
+        match arg.as_str() {
+            "-o" | "--out" => output_dir = Some(flag_value(arg, rest.next())?.into()),
+            "-c" | "--config" => config_path = Some(flag_value(arg, rest.next())?.into()),
+            "-w" | "--watch" if command == "build" => watch = true,
+            "-s" | "--strict" if matches!(command.as_str(), "build" | "serve") => strict = true,
+            "--deny-warnings" if matches!(command.as_str(), "build" | "check" | "serve") => {
+                deny_warnings = true
+            }
+            "-p" | "--port" if command == "serve" => {
+                let value = flag_value(arg, rest.next())?;
+                port = value
+                    .parse()
+                    .map_err(|_| usage_error(format!("invalid port '{}'", value)))?;
+            }
+            "-j" | "--jobs" if matches!(command.as_str(), "build" | "check" | "serve") => {
+                let value = flag_value(arg, rest.next())?;
+                jobs = value
+                    .parse::<NonZeroUsize>()
+                    .map_err(|_| usage_error(format!("invalid job count '{}'", value)))?
+                    .get();
+            }
+            flag if flag.starts_with('-') => {
+                return Err(usage_error(format!("unknown option '{}'", flag)));
+            }
+            path if source_dir.is_none() => source_dir = Some(path.into()),
+            extra => return Err(usage_error(format!("unexpected argument '{}'", extra))),
+        }
+    }
+
+    let source_dir = source_dir.unwrap_or_else(|| PathBuf::from("."));
+    Ok(make_command(SiteArgs {
+        output_dir: output_dir.unwrap_or_else(|| source_dir.join("output")),
+        config_path: config_path.unwrap_or_else(|| source_dir.join("frankmark.toml")),
+        source_dir,
+        watch,
+        port,
+        jobs,
+        strict,
+        deny_warnings,
+    }))
+}
+
+fn flag_value<'a>(flag: &str, value: Option<&'a String>) -> FrankmarkResult<&'a str> {
+    value
+        .map(|value| value.as_str())
+        .ok_or_else(|| usage_error(format!("option '{}' requires a value", flag)))
+}
+
+fn usage_error(message: String) -> FrankmarkError {
+    FrankmarkError::UsageError(format!("{}\n\n{}", message, USAGE))
+}
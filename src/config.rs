@@ -1,25 +1,315 @@
-use std::fs;
+use std::{
+    cmp::Ordering,
+    fs,
+    path::{Path, PathBuf},
+};
 
 use indexmap::IndexMap;
+use markdown::{FrankenUiClasses, FrankenUiOptions};
 use serde::Deserialize;
 
-use crate::error::FrankmarkResult;
+use crate::error::{FrankmarkError, FrankmarkResult};
+use crate::utils;
 
 #[derive(Debug, Deserialize)]
 pub struct Config {
     pub book: Book,
-    pub directories: IndexMap<String, Vec<String>>,
+    #[serde(default)]
+    pub build: BuildConfig,
+    #[serde(default)]
+    pub theme: ThemeConfig,
+    // When omitted, folders and pages are discovered from the source directory
+    pub directories: Option<IndexMap<String, DirectoryEntry>>,
 }
 
+// A folder in `[directories]`: either its list of pages, or a table of
+// subfolders. Keys may be paths such as `"Guides/Deployment"`, and inside a
+// table the key `"."` lists the pages of the table's own folder.
 #[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum DirectoryEntry {
+    Pages(Vec<String>),
+    Folders(IndexMap<String, DirectoryEntry>),
+}
+
+// A folder of the resolved `[directories]` tree
+#[derive(Debug, Default)]
+pub struct FolderSpec {
+    pub name: String,
+    pub pages: Vec<String>,
+    pub folders: Vec<FolderSpec>,
+    // Discovered folders order their pages by frontmatter `order` once parsed
+    pub discovered: bool,
+}
+
+impl FolderSpec {
+    fn add_entries(&mut self, entries: &IndexMap<String, DirectoryEntry>) {
+        for (key, entry) in entries {
+            let mut folder = &mut *self;
+            for segment in key
+                .split('/')
+                .filter(|segment| !segment.is_empty() && *segment != ".")
+            {
+                folder = folder.child(segment);
+            }
+
+            match entry {
+                DirectoryEntry::Pages(pages) => folder.pages.extend(pages.iter().cloned()),
+                DirectoryEntry::Folders(children) => folder.add_entries(children),
+            }
+        }
+    }
+
+    fn child(&mut self, name: &str) -> &mut FolderSpec {
+        let index = match self.folders.iter().position(|folder| folder.name == name) {
+            Some(index) => index,
+            None => {
+                self.folders.push(FolderSpec {
+                    name: name.to_string(),
+                    ..FolderSpec::default()
+                });
+                self.folders.len() - 1
+            }
+        };
+        &mut self.folders[index]
+    }
+
+    // Walk a directory, taking every markdown file as a page and every
+    // subdirectory as a folder, sorted by numeric prefix and then by name.
+    // Hidden entries and the `skip` directories, given as canonical paths, are
+    // left out.
+    fn discover(dir: &Path, name: String, skip: &[PathBuf]) -> FrankmarkResult<FolderSpec> {
+        let mut entries = fs::read_dir(dir)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<Vec<_>, _>>()?;
+        entries.sort_by(|a, b| compare_file_names(a, b));
+
+        let mut folder = FolderSpec {
+            name,
+            discovered: true,
+            ..FolderSpec::default()
+        };
+        for path in entries {
+            let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else {
+                continue;
+            };
+            if file_name.starts_with('.') {
+                continue;
+            }
+
+            if path.is_dir() {
+                if skip.contains(&utils::canonical_path(&path)) {
+                    continue;
+                }
+                let child = FolderSpec::discover(&path, file_name.to_string(), skip)?;
+                if !child.pages.is_empty() || !child.folders.is_empty() {
+                    folder.folders.push(child);
+                }
+            } else if path
+                .extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("md"))
+                && let Some(stem) = path.file_stem().and_then(|stem| stem.to_str())
+            {
+                folder.pages.push(stem.to_string());
+            }
+        }
+        Ok(folder)
+    }
+}
+
+// `02-setup` sorts before `10-deploy`, and both before unnumbered names
+fn compare_file_names(a: &Path, b: &Path) -> Ordering {
+    let key = |path: &Path| {
+        let name = path.file_stem().unwrap_or_default().to_string_lossy();
+        let (number, rest) = utils::split_order_prefix(&name);
+        (
+            number.is_none(),
+            number,
+            rest.to_lowercase(),
+            name.to_string(),
+        )
+    };
+    key(a).cmp(&key(b))
+}
+
+impl Config {
+    // Options of the FrankenUI output, from `[build]` and `[theme]`
+    pub fn frankenui_options(&self) -> FrankenUiOptions {
+        FrankenUiOptions {
+            code_language_badge: self.build.code_language_badge,
+            code_copy_button: self.build.code_copy_button,
+            heading_rule: self.build.heading_rule,
+            classes: self.theme.classes.clone(),
+        }
+    }
+
+    // Top-level folders, in the order they first appear in the config, or as
+    // discovered on disk when `[directories]` is omitted. Markdown files at the
+    // top of the source directory are not part of any folder and are ignored,
+    // as are the output and static directories.
+    pub fn folder_tree(
+        &self,
+        source_dir: &Path,
+        output_dir: &Path,
+    ) -> FrankmarkResult<Vec<FolderSpec>> {
+        match &self.directories {
+            Some(directories) => {
+                let mut root = FolderSpec::default();
+                root.add_entries(directories);
+                Ok(root.folders)
+            }
+            None => {
+                let skip = [
+                    utils::canonical_path(output_dir),
+                    utils::canonical_path(&self.static_dir(source_dir)),
+                ];
+                Ok(FolderSpec::discover(source_dir, String::new(), &skip)?.folders)
+            }
+        }
+    }
+
+    pub fn static_dir(&self, source_dir: &Path) -> PathBuf {
+        source_dir.join(&self.build.static_dir)
+    }
+}
+
+// How the site is built, under `[build]`
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct BuildConfig {
+    // Copied verbatim to the root of the output directory
+    pub static_dir: PathBuf,
+    // Rendered as the root `index.html` when it exists, otherwise the root
+    // redirects to the first page
+    pub landing_page: PathBuf,
+    // Write pages as `<folder>/<page>/index.html` and link to `/folder/page/`
+    pub clean_urls: bool,
+    pub highlight: HighlightMode,
+    // Show the language of code blocks, and a button to copy them
+    pub code_language_badge: bool,
+    pub code_copy_button: bool,
+    // Draw a rule under level 1 and 2 headings
+    pub heading_rule: bool,
+}
+
+// Where code blocks are highlighted
+#[derive(Debug, Clone, Copy, PartialEq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HighlightMode {
+    // While building, as class-based spans styled by `highlight.css`
+    #[default]
+    Build,
+    // In the browser, by highlight.js
+    Client,
+}
+
+impl Default for BuildConfig {
+    fn default() -> Self {
+        Self {
+            static_dir: PathBuf::from("static"),
+            landing_page: PathBuf::from("index.md"),
+            clean_urls: false,
+            highlight: HighlightMode::Build,
+            code_language_badge: true,
+            code_copy_button: true,
+            heading_rule: true,
+        }
+    }
+}
+
+// How pages look, under `[theme]`
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(default)]
+pub struct ThemeConfig {
+    // Classes of rendered elements by name, such as `heading_2` or `table`,
+    // replacing the FrankenUI defaults
+    #[serde(with = "ClassesConfig")]
+    pub classes: FrankenUiClasses,
+}
+
+// `[theme.classes]`, with the snake_case names of the rest of the
+// configuration rather than the camelCase ones markdown-rs serializes
+// `FrankenUiClasses` with. Missing elements keep their default classes, and
+// unknown ones are an error.
+#[derive(Deserialize)]
+#[serde(
+    remote = "FrankenUiClasses",
+    default = "FrankenUiClasses::default",
+    deny_unknown_fields
+)]
+struct ClassesConfig {
+    paragraph: String,
+    heading_1: String,
+    heading_2: String,
+    heading_3: String,
+    heading_4: String,
+    heading_5: String,
+    heading_6: String,
+    heading_rule: String,
+    thematic_break: String,
+    block_quote: String,
+    list_ordered: String,
+    list_unordered: String,
+    task_list_item_check: String,
+    emphasis: String,
+    strong: String,
+    strikethrough: String,
+    code_text: String,
+    link: String,
+    table_wrapper: String,
+    table: String,
+    table_cell_left: String,
+    table_cell_right: String,
+    table_cell_center: String,
+    code_block: String,
+    code_block_inner: String,
+    code_block_card: String,
+    code_block_title: String,
+    code_block_toolbar: String,
+    code_block_badge: String,
+    code_block_copy_button: String,
+    code_line: String,
+    code_line_highlighted: String,
+    code_line_number: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct Book {
     pub title: String,
     pub description: Option<String>,
     pub author: Option<String>,
     pub github_url: Option<String>,
+    // Public URL of the site, such as `https://docs.example.com`, for the
+    // sitemap and canonical links
+    pub base_url: Option<String>,
+    // Path the site is served under, such as `/teams/docs/`, when it differs
+    // from the path of `base_url`
+    pub base_path: Option<String>,
 }
 
 impl Book {
+    // Path of the site root, starting and ending with `/`: `base_path` when
+    // set, otherwise the path of `base_url`
+    pub fn base_path(&self) -> String {
+        let path = match (&self.base_path, &self.base_url) {
+            (Some(base_path), _) => base_path.as_str(),
+            (None, Some(base_url)) => split_url(base_url).1,
+            (None, None) => "",
+        };
+        let path = path.trim_matches('/');
+        if path.is_empty() {
+            "/".to_string()
+        } else {
+            format!("/{}/", path)
+        }
+    }
+
+    // Public URL of the site root, ending with `/`, when there is a `base_url`
+    pub fn site_url(&self) -> Option<String> {
+        let (origin, _) = split_url(self.base_url.as_deref()?);
+        Some(format!("{}{}", origin, self.base_path()))
+    }
+
     pub fn get_title_header(&self) -> String {
         // max lenght 8 characters
         // if more than 8 characters, add '...'
@@ -31,8 +321,20 @@ impl Book {
     }
 }
 
-pub fn parse_config(config_path: &str) -> FrankmarkResult<Config> {
-    let config_content = fs::read_to_string(config_path)?;
-    let config: Config = toml::from_str(&config_content)?;
+// Split `https://host/path` into `https://host` and `/path`
+fn split_url(url: &str) -> (&str, &str) {
+    let host_start = url.find("://").map_or(0, |index| index + 3);
+    let path_start = url[host_start..]
+        .find('/')
+        .map_or(url.len(), |index| host_start + index);
+    url.split_at(path_start)
+}
+
+pub fn parse_config(config_path: &Path) -> FrankmarkResult<Config> {
+    let config_content = fs::read_to_string(config_path).map_err(|e| {
+        FrankmarkError::ConfigError(format!("cannot read {}: {}", config_path.display(), e))
+    })?;
+    let config: Config = toml::from_str(&config_content)
+        .map_err(|e| FrankmarkError::DeserializationError(config_path.to_path_buf(), e))?;
     Ok(config)
 }
@@ -0,0 +1,369 @@
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io::{self, IsTerminal};
+use std::ops::Range;
+use std::path::PathBuf;
+
+use markdown::message::Place;
+
+use crate::error::{FrankmarkError, FrankmarkResult};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+
+// One problem with the site, and the file or folder it is about
+#[derive(Debug)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub path: PathBuf,
+    pub error: FrankmarkError, // What went wrong, which also tells its kind
+    pub hint: Option<String>,  // How to fix it
+}
+
+impl Diagnostic {
+    pub fn new(severity: Severity, path: PathBuf, error: FrankmarkError) -> Self {
+        let hint = hint(&error).map(str::to_string);
+        Self {
+            severity,
+            path,
+            error,
+            hint,
+        }
+    }
+
+    // Replace the hint that goes with the kind of error, for callers that know
+    // more about what went wrong
+    pub fn hint(&mut self, hint: impl Into<String>) -> &mut Self {
+        self.hint = Some(hint.into());
+        self
+    }
+
+    // The problem as rustc would show it: a headline, where it is, the lines of
+    // the file it is on with a caret under it, and a hint
+    //
+    //     error: Deserialization error: invalid type: string "x", expected a boolean
+    //       --> docs/frankmark.toml:4:14
+    //        |
+    //      4 | clean_urls = "x"
+    //        |              ^^^
+    //        = hint: see the Configuration section of the README for every key
+    pub fn render(&self, color: bool) -> String {
+        let paint = |style: &str, text: &str| {
+            if color {
+                format!("\x1b[{}m{}\x1b[0m", style, text)
+            } else {
+                text.to_string()
+            }
+        };
+        let severity_style = match self.severity {
+            Severity::Warning => YELLOW,
+            Severity::Error => RED,
+        };
+
+        let mut out = format!(
+            "{}: {}\n",
+            paint(severity_style, &self.severity.to_string()),
+            paint(BOLD, &headline(&self.error))
+        );
+
+        let source = fs::read_to_string(&self.path).ok();
+        let snippet = source
+            .as_deref()
+            .zip(error_span(&self.error))
+            .map(|(source, span)| Snippet::new(source, span));
+        let Some(snippet) = snippet else {
+            out.push_str(&format!(
+                "  {} {}\n",
+                paint(BLUE, "-->"),
+                self.path.display()
+            ));
+            if let Some(hint) = &self.hint {
+                out.push_str(&format!("  {} {}\n", paint(BLUE, "= hint:"), hint));
+            }
+            return out;
+        };
+
+        let gutter = " ".repeat(snippet.last_line_number().to_string().len());
+        out.push_str(&format!(
+            "{}{} {}:{}:{}\n",
+            gutter,
+            paint(BLUE, "-->"),
+            self.path.display(),
+            snippet.line_number,
+            snippet.column
+        ));
+        out.push_str(&format!("{} {}\n", gutter, paint(BLUE, "|")));
+        for (index, line) in snippet.lines.iter().enumerate() {
+            let number = format!(
+                "{:>width$}",
+                snippet.line_number + index,
+                width = gutter.len()
+            );
+            out.push_str(&format!(
+                "{} {} {}\n",
+                paint(BLUE, &number),
+                paint(BLUE, "|"),
+                line
+            ));
+            if index == 0 {
+                out.push_str(&format!(
+                    "{} {} {}{}\n",
+                    gutter,
+                    paint(BLUE, "|"),
+                    snippet.indent,
+                    paint(severity_style, &"^".repeat(snippet.carets))
+                ));
+            }
+        }
+        if let Some(hint) = &self.hint {
+            out.push_str(&format!("{} {} {}\n", gutter, paint(BLUE, "= hint:"), hint));
+        }
+        out
+    }
+}
+
+const BOLD: &str = "1";
+const RED: &str = "1;31";
+const YELLOW: &str = "1;33";
+const BLUE: &str = "1;34";
+
+// Colors only for a person reading a terminal, and never with `NO_COLOR` set
+pub fn use_color() -> bool {
+    io::stderr().is_terminal() && env::var_os("NO_COLOR").is_none_or(|value| value.is_empty())
+}
+
+// Print an error that stopped a command, with the lines it is about when it
+// points into a file
+pub fn print_error(error: FrankmarkError) {
+    match &error {
+        FrankmarkError::DeserializationError(path, _) => {
+            let diagnostic = Diagnostic::new(Severity::Error, path.clone(), error);
+            eprint!("{}", diagnostic.render(use_color()));
+        }
+        _ => eprintln!("Error: {}", error),
+    }
+}
+
+// What went wrong, leaving out where, which the snippet shows
+fn headline(error: &FrankmarkError) -> String {
+    match error {
+        FrankmarkError::MarkdownError(message) => format!(
+            "Markdown error: {} ({}:{})",
+            message.reason, message.source, message.rule_id
+        ),
+        other => other.to_string(),
+    }
+}
+
+// Byte range of the source an error points at, when it knows
+fn error_span(error: &FrankmarkError) -> Option<Range<usize>> {
+    match error {
+        FrankmarkError::DeserializationError(_, error) => error.span(),
+        FrankmarkError::MarkdownError(message) => match message.place.as_deref()? {
+            Place::Position(position) => Some(position.start.offset..position.end.offset),
+            Place::Point(point) => Some(point.offset..point.offset),
+        },
+        _ => None,
+    }
+}
+
+// The hint for a kind of error. Files and directories can be missing or
+// unreadable for many reasons, so their hints come from where they are
+// reported.
+fn hint(error: &FrankmarkError) -> Option<&'static str> {
+    match error {
+        FrankmarkError::DeserializationError(..) => {
+            Some("see the Configuration section of the README for every key and its type")
+        }
+        FrankmarkError::MarkdownError(message) => match message.rule_id.as_str() {
+            "missing-page" | "missing-file" => {
+                Some("links are relative to the page they are on; check the spelling")
+            }
+            "unlisted-page" => Some("add the page to [directories] in frankmark.toml"),
+            "outside-page" => Some("only pages in a folder of the source directory are built"),
+            "draft-page" => Some("publish the page by removing `draft: true` from it"),
+            "failed-page" => Some("fix the errors reported for the page"),
+            "missing-heading" => {
+                Some("heading ids are lowercase with dashes, such as `#getting-started`")
+            }
+            "duplicate-id" => Some("give one of the headings another `{#id}`"),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+// The lines a span covers, at most `MAX_SNIPPET_LINES` of them, and where its
+// caret goes on the first
+struct Snippet<'a> {
+    lines: Vec<&'a str>,
+    line_number: usize, // 1-based, like the column
+    column: usize,
+    indent: String, // Whitespace up to the span, keeping tabs so it lines up
+    carets: usize,
+}
+
+const MAX_SNIPPET_LINES: usize = 3;
+
+impl<'a> Snippet<'a> {
+    fn new(source: &'a str, span: Range<usize>) -> Self {
+        let start = floor_char_boundary(source, span.start);
+        let end = floor_char_boundary(source, span.end).max(start);
+
+        let line_start = source[..start].rfind('\n').map_or(0, |index| index + 1);
+        let line_number = source[..start].matches('\n').count() + 1;
+        let prefix = &source[line_start..start];
+
+        // A span ending at a line ending does not cover the next line
+        let covered = source[start..end].trim_end_matches(['\n', '\r']);
+        let lines: Vec<&str> = source[line_start..]
+            .lines()
+            .take((covered.matches('\n').count() + 1).min(MAX_SNIPPET_LINES))
+            .collect();
+
+        let first_line = lines.first().copied().unwrap_or_default();
+        let rest_of_line = first_line.get(prefix.len()..).unwrap_or_default();
+        let carets = covered
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .chars()
+            .count()
+            .min(rest_of_line.chars().count())
+            .max(1);
+
+        Self {
+            lines,
+            line_number,
+            column: prefix.chars().count() + 1,
+            indent: prefix
+                .chars()
+                .map(|c| if c == '\t' { '\t' } else { ' ' })
+                .collect(),
+            carets,
+        }
+    }
+
+    fn last_line_number(&self) -> usize {
+        self.line_number + self.lines.len().saturating_sub(1)
+    }
+}
+
+fn floor_char_boundary(source: &str, index: usize) -> usize {
+    let mut index = index.min(source.len());
+    while !source.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+// Every problem found while building, so a build reports all of them at once
+// instead of stopping at the first
+#[derive(Debug, Default)]
+pub struct Diagnostics {
+    items: Vec<Diagnostic>,
+}
+
+impl Diagnostics {
+    pub fn warn(&mut self, path: impl Into<PathBuf>, error: FrankmarkError) -> &mut Diagnostic {
+        self.push(Severity::Warning, path.into(), error)
+    }
+
+    pub fn error(&mut self, path: impl Into<PathBuf>, error: FrankmarkError) -> &mut Diagnostic {
+        self.push(Severity::Error, path.into(), error)
+    }
+
+    pub fn push(
+        &mut self,
+        severity: Severity,
+        path: PathBuf,
+        error: FrankmarkError,
+    ) -> &mut Diagnostic {
+        self.items.push(Diagnostic::new(severity, path, error));
+        self.items.last_mut().unwrap()
+    }
+
+    pub fn count(&self, severity: Severity) -> usize {
+        self.items
+            .iter()
+            .filter(|diagnostic| diagnostic.severity == severity)
+            .count()
+    }
+
+    // Print every problem grouped by the file it is about, in the order the
+    // files were first reported
+    pub fn print_summary(&self) {
+        if self.items.is_empty() {
+            return;
+        }
+
+        eprintln!("Found {}", self.counts().join(" and "));
+        let mut paths: Vec<&PathBuf> = Vec::new();
+        for diagnostic in &self.items {
+            if !paths.contains(&&diagnostic.path) {
+                paths.push(&diagnostic.path);
+            }
+        }
+        let color = use_color();
+        for path in paths {
+            for diagnostic in self.items.iter().filter(|item| &item.path == path) {
+                eprint!("\n{}", diagnostic.render(color));
+            }
+        }
+    }
+
+    // Whether the build has to stop: on any error, or on any problem at all
+    // when warnings are denied
+    pub fn fails(&self, deny_warnings: bool) -> bool {
+        self.count(Severity::Error) > 0 || (deny_warnings && !self.items.is_empty())
+    }
+
+    // Fail with how many problems `command` ran into, which `print_summary`
+    // has already shown, and the exit code of the first error, or of the
+    // first warning when warnings are denied
+    pub fn into_result(self, command: &str, deny_warnings: bool) -> FrankmarkResult<()> {
+        let first = self
+            .items
+            .iter()
+            .find(|diagnostic| diagnostic.severity == Severity::Error)
+            .or_else(|| self.items.first().filter(|_| deny_warnings));
+        match first {
+            Some(diagnostic) => Err(FrankmarkError::DiagnosticsError(
+                format!("{} failed: {}", command, self.counts().join(", ")),
+                diagnostic.error.exit_code(),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    // `2 errors`, `1 warning`, leaving out severities with no problems
+    fn counts(&self) -> Vec<String> {
+        [(Severity::Error, "error"), (Severity::Warning, "warning")]
+            .into_iter()
+            .map(|(severity, noun)| (self.count(severity), noun))
+            .filter(|&(count, _)| count > 0)
+            .map(|(count, noun)| plural(count, noun))
+            .collect()
+    }
+}
+
+fn plural(count: usize, noun: &str) -> String {
+    if count == 1 {
+        format!("1 {}", noun)
+    } else {
+        format!("{} {}s", count, noun)
+    }
+}
@@ -1,21 +1,26 @@
-use askama;
 use std::error::Error;
+use std::path::PathBuf;
 use thiserror::Error;
 
 // Custom error type for Frankmark using thiserror
 #[derive(Error, Debug)]
+#[allow(clippy::enum_variant_names)]
 pub enum FrankmarkError {
+    #[error("{0}")]
+    UsageError(String),
+
     #[error("Configuration error: {0}")]
     ConfigError(String),
 
     #[error("Directory error: {0}")]
-    #[allow(dead_code)]
     DirectoryError(String),
 
     #[error("File error: {0}")]
-    #[allow(dead_code)]
     FileError(String),
 
+    #[error("Content error: {0}")]
+    ContentError(String),
+
     #[error("Template error: {0}")]
     TemplateError(String),
 
@@ -23,13 +28,35 @@ pub enum FrankmarkError {
     IoError(#[from] std::io::Error),
 
     #[error("Inner error: {0}")]
-    InnerError(Box<dyn Error>),
+    InnerError(Box<dyn Error + Send + Sync>),
 
-    #[error("Deserialization error: {0}")]
-    DeserializationError(#[from] toml::de::Error),
+    // The configuration file and what is wrong in it
+    #[error("Deserialization error: {}", .1.message())]
+    DeserializationError(PathBuf, toml::de::Error),
 
     #[error("Markdown error: {0}")]
     MarkdownError(markdown::message::Message),
+
+    // Problems that were already shown, and the exit code of the one that
+    // failed the command
+    #[error("{0}")]
+    DiagnosticsError(String, i32),
+}
+
+impl FrankmarkError {
+    // Process exit code, so scripts can tell what kind of failure happened
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            FrankmarkError::UsageError(_) => 64,
+            FrankmarkError::ConfigError(_) | FrankmarkError::DeserializationError(..) => 2,
+            FrankmarkError::IoError(_)
+            | FrankmarkError::DirectoryError(_)
+            | FrankmarkError::FileError(_) => 3,
+            FrankmarkError::ContentError(_) | FrankmarkError::MarkdownError(_) => 4,
+            FrankmarkError::TemplateError(_) | FrankmarkError::InnerError(_) => 1,
+            FrankmarkError::DiagnosticsError(_, exit_code) => *exit_code,
+        }
+    }
 }
 
 impl From<askama::Error> for FrankmarkError {
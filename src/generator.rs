@@ -1,78 +1,497 @@
 use std::{
+    collections::hash_map::DefaultHasher,
+    collections::{HashMap, HashSet},
     fs::{self, File},
+    hash::{Hash, Hasher},
     io::Write,
-    path::Path,
+    path::{Path, PathBuf},
 };
 
-use crate::error::FrankmarkResult;
+use crate::assets;
+use crate::cli::SiteArgs;
+use crate::config::{Book, BuildConfig, Config, HighlightMode, ThemeConfig, parse_config};
+use crate::diagnostics::{Diagnostics, Severity};
+use crate::error::{FrankmarkError, FrankmarkResult};
+use crate::highlight;
+use crate::models::{self, Folder, LeftOutPages, Page, PageMeta};
 use crate::navigation::PageNavigator;
-use crate::parser::parse_directory;
-use crate::template::MainTemplate;
+use crate::parser::{PageCache, parse_directory, parse_landing_page};
+use crate::search;
+use crate::sitemap;
+use crate::template::{MainTemplate, RedirectTemplate};
+use crate::utils;
+use crate::validate::find_broken_links;
 use askama::Template;
 
-// Optimized site generation with better file handling
-pub fn generate_site(folder_path: &str) -> FrankmarkResult<()> {
-    let config_path = format!("{}/frankmark.toml", folder_path);
-    let config = crate::config::parse_config(&config_path)?;
+// Load the configuration and parse every page, without writing anything
+fn load_site(
+    args: &SiteArgs,
+    diagnostics: &mut Diagnostics,
+) -> FrankmarkResult<(Config, Vec<Folder>, LeftOutPages)> {
+    let config = parse_config(&args.config_path)?;
     println!("Configuration loaded successfully");
 
-    let source_dir = folder_path;
-    let mut folders = parse_directory(&config, source_dir)?;
+    let (folders, left_out) =
+        parse_directory(&config, args, &mut PageCache::default(), diagnostics)?;
     println!("Found {} folders to process", folders.len());
 
-    let output_path = Path::new(folder_path).join("output");
+    Ok((config, folders, left_out))
+}
 
-    // Efficient directory cleanup and creation
-    if output_path.exists() {
-        fs::remove_dir_all(&output_path)?;
-    }
-    fs::create_dir_all(&output_path)?;
+// Optimized site generation with better file handling
+pub fn generate_site(args: &SiteArgs) -> FrankmarkResult<()> {
+    Site::new(args).build()
+}
 
-    // Set output paths for all pages
-    for folder in &mut folders {
-        for page in &mut folder.pages {
-            page.output_path = output_path
-                .join(&folder.name)
-                .join(format!("{}.html", page.display_name));
+// State kept between builds, so watch mode only redoes the work a change
+// affects. The template is compiled into the binary, so a template change
+// always starts from a fresh `Site`.
+pub struct Site<'a> {
+    args: &'a SiteArgs,
+    live_reload: bool,
+    book: Option<Book>,
+    build: Option<BuildConfig>,
+    theme: Option<ThemeConfig>,
+    page_cache: PageCache,
+    rendered: HashMap<PathBuf, u64>, // Output path -> fingerprint of what was written
+    copied: HashSet<PathBuf>,        // Output paths of static files and page assets
+    sitemap: Option<u64>,            // Fingerprint of what the sitemap was written from
+}
+
+impl<'a> Site<'a> {
+    pub fn new(args: &'a SiteArgs) -> Self {
+        Self {
+            args,
+            live_reload: false,
+            book: None,
+            build: None,
+            theme: None,
+            page_cache: PageCache::default(),
+            rendered: HashMap::new(),
+            copied: HashSet::new(),
+            sitemap: None,
         }
     }
 
-    // Pre-compute navigation for better performance
-    let navigator = PageNavigator::new(&folders);
+    // Inject the preview server's live reload script into every page
+    pub fn with_live_reload(mut self) -> Self {
+        self.live_reload = true;
+        self
+    }
 
-    let mut total_pages = 0;
-    let mut first_page: Option<&crate::models::Page> = None;
+    pub fn args(&self) -> &'a SiteArgs {
+        self.args
+    }
 
-    // Generate all pages efficiently
-    for folder in &folders {
-        let folder_output_path = output_path.join(&folder.name);
-        fs::create_dir_all(&folder_output_path)?;
+    pub fn build(&mut self) -> FrankmarkResult<()> {
+        let config = parse_config(&self.args.config_path)?;
+        println!("Configuration loaded successfully");
 
-        for page in &folder.pages {
-            let page_template = MainTemplate::new(
+        let output_path = &self.args.output_dir;
+        check_output_dir(self.args, &config.static_dir(&self.args.source_dir))?;
+
+        // Anything outside of [directories] can change every page
+        if self.book.as_ref() != Some(&config.book)
+            || self.build.as_ref() != Some(&config.build)
+            || self.theme.as_ref() != Some(&config.theme)
+        {
+            self.page_cache.clear();
+            self.rendered.clear();
+            self.copied.clear();
+            self.sitemap = None;
+
+            // Efficient directory cleanup and creation
+            if output_path.exists() {
+                fs::remove_dir_all(output_path)?;
+            }
+        }
+        fs::create_dir_all(output_path)?;
+
+        let mut diagnostics = Diagnostics::default();
+        let (folders, left_out) =
+            parse_directory(&config, self.args, &mut self.page_cache, &mut diagnostics)?;
+        println!("Found {} folders to process", folders.len());
+
+        // Broken links only warn, unless the build is strict
+        let link_severity = if self.args.strict {
+            Severity::Error
+        } else {
+            Severity::Warning
+        };
+        check_pages(
+            &config,
+            &folders,
+            &left_out,
+            link_severity,
+            &mut diagnostics,
+        );
+
+        // Nothing is written once the build is bound to fail
+        if diagnostics.fails(self.args.deny_warnings) {
+            diagnostics.print_summary();
+            return diagnostics.into_result("build", self.args.deny_warnings);
+        }
+
+        // Pre-compute navigation for better performance
+        let navigator = PageNavigator::new(&folders);
+        let sidebar = sidebar_fingerprint(&folders);
+
+        let mut rendered = HashMap::new();
+        let mut changed_pages = Vec::new();
+
+        for &page in navigator.pages() {
+            let previous_page = navigator.get_previous_page(page);
+            let next_page = navigator.get_next_page(page);
+
+            let fingerprint = page_fingerprint(sidebar, page, previous_page, next_page);
+            rendered.insert(page.output_path.clone(), fingerprint);
+
+            // A page deleted from the output by hand is written again
+            if self.rendered.get(&page.output_path) != Some(&fingerprint)
+                || !page.output_path.exists()
+            {
+                changed_pages.push((page, previous_page, next_page));
+            }
+        }
+        let total_pages = navigator.pages().len();
+        let unchanged_pages = total_pages - changed_pages.len();
+
+        // Render and write in parallel, then report in navigation order
+        let results = utils::parallel_map(
+            &changed_pages,
+            self.args.jobs,
+            |&(page, previous_page, next_page)| {
+                let mut page_template = MainTemplate::new(
+                    &config.book,
+                    &folders,
+                    output_path,
+                    page,
+                    previous_page,
+                    next_page,
+                );
+                page_template.live_reload = self.live_reload;
+                page_template.clean_urls = config.build.clean_urls;
+                page_template.highlight = config.build.highlight;
+                write_page(page, &page_template.render()?)
+            },
+        );
+        for ((page, _, _), result) in changed_pages.iter().zip(results) {
+            result?;
+            println!("Generated {}", page.output_path.display());
+        }
+
+        // The root index and the 404 page, written only when they change
+        let landing_page = parse_landing_page(&config, self.args, &self.page_cache)?;
+        for (path, html) in
+            self.render_site_pages(&config, &folders, &navigator, landing_page.as_ref())?
+        {
+            let mut hasher = DefaultHasher::new();
+            html.hash(&mut hasher);
+            let fingerprint = hasher.finish();
+            if self.rendered.get(&path) != Some(&fingerprint) || !path.exists() {
+                fs::write(&path, html)?;
+                println!("Generated {}", path.display());
+            }
+            rendered.insert(path, fingerprint);
+        }
+
+        search::write_search_index(navigator.pages(), output_path, config.build.clean_urls)?;
+        if config.build.highlight == HighlightMode::Build {
+            highlight::write_stylesheet(output_path)?;
+        }
+
+        // Looking up when pages were last committed runs `git`, so the
+        // sitemap is only rewritten when a page was added, moved or touched
+        let static_dir = config.static_dir(&self.args.source_dir);
+        let mut sitemap = None;
+        if let Some(site_url) = config.book.site_url() {
+            let sitemap_pages: Vec<&Page> = landing_page
+                .iter()
+                .chain(navigator.pages().iter().copied())
+                .collect();
+            let fingerprint = sitemap_fingerprint(&site_url, &sitemap_pages, &static_dir);
+            if self.sitemap != Some(fingerprint)
+                || !output_path.join(sitemap::SITEMAP_FILE).exists()
+            {
+                sitemap::write_sitemap(
+                    &sitemap_pages,
+                    &site_url,
+                    &config.book.base_path(),
+                    &self.args.source_dir,
+                    output_path,
+                    &static_dir,
+                )?;
+            }
+            sitemap = Some(fingerprint);
+        }
+
+        // Static files, then every local file a page links to
+        let mut copied = 0;
+        let mut copied_paths = HashSet::new();
+        if static_dir.is_dir() {
+            copied += assets::copy_dir(&static_dir, output_path, &mut copied_paths)?;
+        }
+        copied += assets::copy_page_assets(
+            landing_page.iter().chain(navigator.pages().iter().copied()),
+            &self.args.source_dir,
+            output_path,
+            &mut copied_paths,
+        )?;
+        if copied > 0 {
+            println!("Copied {} assets", copied);
+        }
+
+        // Remove pages that are no longer part of the site, and files that
+        // are no longer linked or were deleted from the source
+        let stale_paths = self
+            .rendered
+            .keys()
+            .chain(&self.copied)
+            .filter(|path| !rendered.contains_key(*path) && !copied_paths.contains(*path));
+        for stale_path in stale_paths {
+            if stale_path.exists() {
+                fs::remove_file(stale_path)?;
+                println!("Removed {}", stale_path.display());
+            }
+        }
+
+        self.rendered = rendered;
+        self.copied = copied_paths;
+        self.sitemap = sitemap;
+        self.book = Some(config.book);
+        self.build = Some(config.build);
+        self.theme = Some(config.theme);
+
+        if unchanged_pages > 0 {
+            println!(
+                "Successfully generated {} pages ({} unchanged)",
+                total_pages - unchanged_pages,
+                unchanged_pages
+            );
+        } else {
+            println!("Successfully generated {} pages", total_pages);
+        }
+        diagnostics.print_summary();
+        Ok(())
+    }
+}
+
+impl Site<'_> {
+    // Render the pages every site has at the root of the output directory:
+    // the landing page, or a redirect to the first page, and `404.html`
+    fn render_site_pages(
+        &self,
+        config: &Config,
+        folders: &Vec<Folder>,
+        navigator: &PageNavigator,
+        landing_page: Option<&Page>,
+    ) -> FrankmarkResult<Vec<(PathBuf, String)>> {
+        let output_path = &self.args.output_dir;
+        let first_page = navigator.pages().first().copied();
+        let mut pages = Vec::new();
+
+        if let Some(landing_page) = landing_page {
+            let mut template = MainTemplate::new(
                 &config.book,
-                &folders,
-                page,
-                navigator.get_previous_page(page),
-                navigator.get_next_page(page),
+                folders,
+                output_path,
+                landing_page,
+                None,
+                first_page,
+            );
+            template.live_reload = self.live_reload;
+            template.clean_urls = config.build.clean_urls;
+            template.highlight = config.build.highlight;
+            pages.push((landing_page.output_path.clone(), template.render()?));
+        } else if let Some(first_page) = first_page {
+            let url = utils::relative_url(
+                output_path,
+                &first_page.output_path,
+                config.build.clean_urls,
             );
+            let template = RedirectTemplate {
+                title: &config.book.title,
+                url,
+            };
+            pages.push((output_path.join("index.html"), template.render()?));
+        }
 
-            let rendered = page_template.render()?;
-            let file_path = folder_output_path.join(format!("{}.html", page.display_name));
+        // Static hosts serve `404.html` for any missing URL, so it links from
+        // the site root rather than relative to itself
+        let mut not_found = Page::new(
+            PathBuf::new(),
+            "Page not found".to_string(),
+            "<h1 class=\"uk-h1 mt-8 mb-4\">Page not found</h1>\n\
+             <p class=\"uk-paragraph\">The page you are looking for does not exist. \
+             Use the sidebar or search to find what you need.</p>"
+                .to_string(),
+            String::new(),
+            Vec::new(),
+            Vec::new(),
+            PageMeta::default(),
+        );
+        not_found.output_path = output_path.join("404.html");
+        let mut template =
+            MainTemplate::new(&config.book, folders, output_path, &not_found, None, None);
+        template.live_reload = self.live_reload;
+        template.absolute_urls = true;
+        template.clean_urls = config.build.clean_urls;
+        template.highlight = config.build.highlight;
+        template.indexable = false;
+        pages.push((not_found.output_path.clone(), template.render()?));
 
-            // Use buffered writing for better performance
-            let mut file = File::create(&file_path)?;
-            file.write_all(rendered.as_bytes())?;
+        Ok(pages)
+    }
+}
 
-            println!("Generated {}", file_path.display());
-            total_pages += 1;
+fn write_page(page: &Page, html: &str) -> FrankmarkResult<()> {
+    if let Some(parent) = page.output_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
 
-            if first_page.is_none() {
-                first_page = Some(page);
-            }
+    // Use buffered writing for better performance
+    let mut file = File::create(&page.output_path)?;
+    file.write_all(html.as_bytes())?;
+    Ok(())
+}
+
+// Everything the sidebar and command palette show, shared by every page
+fn sidebar_fingerprint(folders: &[Folder]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for folder in models::all_folders(folders) {
+        folder.path.hash(&mut hasher);
+        for page in &folder.pages {
+            page.id.hash(&mut hasher);
+            page.output_path.hash(&mut hasher);
+            page.title().hash(&mut hasher);
+        }
+    }
+    hasher.finish()
+}
+
+// Everything that ends up in a page's rendered HTML
+fn page_fingerprint(
+    sidebar: u64,
+    page: &Page,
+    previous_page: Option<&Page>,
+    next_page: Option<&Page>,
+) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    sidebar.hash(&mut hasher);
+    page.id.hash(&mut hasher);
+    page.content.hash(&mut hasher);
+    page.headings.hash(&mut hasher);
+    page.meta.hash(&mut hasher);
+    for neighbour in [previous_page, next_page] {
+        neighbour
+            .map(|page| (&page.id, page.title(), &page.folder_name, &page.output_path))
+            .hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+// Everything the sitemap and `robots.txt` are written from, with the
+// modification time of each page standing in for its last commit
+fn sitemap_fingerprint(site_url: &str, pages: &[&Page], static_dir: &Path) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    site_url.hash(&mut hasher);
+    static_dir.join("robots.txt").exists().hash(&mut hasher);
+    for page in pages {
+        page.source_path.hash(&mut hasher);
+        page.output_path.hash(&mut hasher);
+        fs::metadata(&page.source_path)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+// Parse every page and validate links, reporting problems without output
+pub fn check_site(args: &SiteArgs) -> FrankmarkResult<()> {
+    let mut diagnostics = Diagnostics::default();
+    let (config, folders, left_out) = load_site(args, &mut diagnostics)?;
+    check_pages(
+        &config,
+        &folders,
+        &left_out,
+        Severity::Error,
+        &mut diagnostics,
+    );
+    diagnostics.print_summary();
+
+    let page_count = models::all_pages(&folders).len();
+    if diagnostics.fails(args.deny_warnings) {
+        return diagnostics.into_result("check", args.deny_warnings);
+    }
+    println!("Checked {} pages, no problems found", page_count);
+    Ok(())
+}
+
+// Record every broken link as a problem of the given severity, and what
+// parsing the pages warned about
+fn check_pages(
+    config: &Config,
+    folders: &[Folder],
+    left_out: &LeftOutPages,
+    severity: Severity,
+    diagnostics: &mut Diagnostics,
+) {
+    let listed = config.directories.is_some();
+    for broken_link in find_broken_links(folders, left_out, listed) {
+        diagnostics.push(
+            severity,
+            broken_link.source_path,
+            FrankmarkError::MarkdownError(broken_link.message),
+        );
+    }
+    for page in models::all_pages(folders) {
+        for message in &page.warnings {
+            diagnostics.warn(
+                &page.source_path,
+                FrankmarkError::MarkdownError(message.clone()),
+            );
         }
     }
+}
+
+// Remove the generated output directory
+pub fn clean_site(args: &SiteArgs) -> FrankmarkResult<()> {
+    // A broken configuration should not keep the output from being cleaned,
+    // so its static directory falls back to the default
+    let static_dir = match parse_config(&args.config_path) {
+        Ok(config) => config.static_dir(&args.source_dir),
+        Err(_) => args.source_dir.join(BuildConfig::default().static_dir),
+    };
+    check_output_dir(args, &static_dir)?;
+
+    if args.output_dir.exists() {
+        fs::remove_dir_all(&args.output_dir)?;
+        println!("Removed {}", args.output_dir.display());
+    } else {
+        println!("Nothing to clean at {}", args.output_dir.display());
+    }
+    Ok(())
+}
 
-    println!("Successfully generated {} pages", total_pages);
+// Building and cleaning remove the output directory, so it must not hold
+// anything the site is built from
+fn check_output_dir(args: &SiteArgs, static_dir: &Path) -> FrankmarkResult<()> {
+    let output_dir = utils::canonical_path(&args.output_dir);
+    let inputs = [
+        ("source directory", args.source_dir.as_path()),
+        ("configuration file", args.config_path.as_path()),
+        ("static directory", static_dir),
+    ];
+    for (name, path) in inputs {
+        if utils::canonical_path(path).starts_with(&output_dir) {
+            return Err(FrankmarkError::ConfigError(format!(
+                "output directory {} would remove the {} {}",
+                args.output_dir.display(),
+                name,
+                path.display()
+            )));
+        }
+    }
     Ok(())
 }
@@ -0,0 +1,123 @@
+use std::{path::Path, sync::OnceLock};
+
+use syntect::{
+    highlighting::ThemeSet,
+    html::{ClassStyle, css_for_theme_with_class_style},
+    parsing::{BasicScopeStackOp, ParseState, Scope, ScopeStack, SyntaxSet},
+    util::LinesWithEndings,
+};
+
+use crate::error::{FrankmarkError, FrankmarkResult};
+use crate::utils;
+
+pub const HIGHLIGHT_CSS_FILE: &str = "highlight.css";
+
+// Themes for FrankenUI's light and dark modes
+const LIGHT_THEME: &str = "InspiredGitHub";
+const DARK_THEME: &str = "base16-ocean.dark";
+
+// Every scope becomes classes such as `hl-keyword hl-control hl-rust`
+const CLASS_PREFIX: &str = "hl-";
+
+// Loading the grammars takes a while, so it is done once, on first use
+fn syntax_set() -> &'static SyntaxSet {
+    static SYNTAX_SET: OnceLock<SyntaxSet> = OnceLock::new();
+    SYNTAX_SET.get_or_init(two_face::syntax::extra_newlines)
+}
+
+// Highlight code as HTML with a span per scope, or `None` when there is no
+// grammar for the language. Every line closes the spans it opens and reopens
+// the ones still open from the line before, so lines stand on their own.
+pub fn highlight(language: &str, code: &str) -> Option<String> {
+    let syntax_set = syntax_set();
+    let syntax = syntax_set.find_syntax_by_token(language)?;
+    let mut state = ParseState::new(syntax);
+    let mut stack = ScopeStack::new();
+    let mut html = String::with_capacity(code.len() * 4);
+
+    for line in LinesWithEndings::from(code) {
+        let ops = state.parse_line(line, syntax_set).ok()?;
+        let text = line.trim_end_matches(['\n', '\r']);
+
+        for scope in stack.as_slice() {
+            open_span(&mut html, *scope);
+        }
+        let mut position = 0;
+        for (index, op) in ops {
+            let index = index.min(text.len());
+            push_escaped(&mut html, &text[position..index]);
+            position = index;
+            stack
+                .apply_with_hook(&op, |op, _| match op {
+                    BasicScopeStackOp::Push(scope) => open_span(&mut html, scope),
+                    BasicScopeStackOp::Pop => html.push_str("</span>"),
+                })
+                .ok()?;
+        }
+        push_escaped(&mut html, &text[position..]);
+        for _ in stack.as_slice() {
+            html.push_str("</span>");
+        }
+        html.push_str(&line[text.len()..]);
+    }
+    Some(html)
+}
+
+fn open_span(html: &mut String, scope: Scope) {
+    html.push_str("<span class=\"");
+    for (index, atom) in scope.build_string().split('.').enumerate() {
+        if index > 0 {
+            html.push(' ');
+        }
+        html.push_str(CLASS_PREFIX);
+        html.push_str(atom);
+    }
+    html.push_str("\">");
+}
+
+fn push_escaped(html: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '&' => html.push_str("&amp;"),
+            '<' => html.push_str("&lt;"),
+            '>' => html.push_str("&gt;"),
+            '"' => html.push_str("&quot;"),
+            c => html.push(c),
+        }
+    }
+}
+
+// Colors for the highlighted code: the light theme unless the page is dark,
+// and the dark theme under FrankenUI's `.dark` class
+pub fn write_stylesheet(output_dir: &Path) -> FrankmarkResult<()> {
+    let themes = ThemeSet::load_defaults();
+    let mut css = String::new();
+    for (theme, scope) in [(LIGHT_THEME, ":root:not(.dark)"), (DARK_THEME, ".dark")] {
+        let theme_css = css_for_theme_with_class_style(
+            &themes.themes[theme],
+            ClassStyle::SpacedPrefixed {
+                prefix: CLASS_PREFIX,
+            },
+        )
+        .map_err(|e| FrankmarkError::InnerError(Box::new(e)))?;
+
+        // Selectors are on their own line, ending with `{`
+        for line in theme_css.lines() {
+            match line.strip_suffix('{') {
+                Some(selectors) => {
+                    let selectors: Vec<String> = selectors
+                        .split(',')
+                        .map(|selector| format!("{} {}", scope, selector.trim()))
+                        .collect();
+                    css.push_str(&selectors.join(", "));
+                    css.push_str(" {");
+                }
+                None => css.push_str(line),
+            }
+            css.push('\n');
+        }
+    }
+
+    utils::write_if_changed(&output_dir.join(HIGHLIGHT_CSS_FILE), &css)?;
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use std::fs;
+
+use crate::cli::SiteArgs;
+use crate::error::{FrankmarkError, FrankmarkResult};
+
+const STARTER_PAGES: [(&str, &str, &str); 2] = [
+    (
+        "Introduction",
+        "Getting-Started",
+        "# Getting Started\n\nWelcome to your new documentation site.\n\n## Next steps\n\nContinue with [Writing Pages](Writing-Pages.md).\n",
+    ),
+    (
+        "Introduction",
+        "Writing-Pages",
+        "# Writing Pages\n\nPages are markdown files listed under `[directories]` in `frankmark.toml`.\n\n## Building\n\nRun `frankmark build` to generate the site into `output/`.\n",
+    ),
+];
+
+// Scaffold a frankmark.toml and a couple of starter pages
+pub fn init_site(args: &SiteArgs) -> FrankmarkResult<()> {
+    if args.config_path.exists() {
+        return Err(FrankmarkError::ConfigError(format!(
+            "{} already exists, refusing to overwrite it",
+            args.config_path.display()
+        )));
+    }
+
+    fs::create_dir_all(&args.source_dir)?;
+    let title = args
+        .source_dir
+        .canonicalize()
+        .ok()
+        .and_then(|path| {
+            path.file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+        })
+        .unwrap_or_else(|| "My Documentation".to_string());
+
+    let mut config = format!(
+        "[book]\ntitle = {}\n\n[directories]\n",
+        toml::Value::String(title)
+    );
+    config.push_str("Introduction = [");
+    let page_list: Vec<String> = STARTER_PAGES
+        .iter()
+        .map(|(_, page, _)| format!("\"{}\"", page))
+        .collect();
+    config.push_str(&page_list.join(", "));
+    config.push_str("]\n");
+
+    for (folder, page, content) in STARTER_PAGES {
+        let page_path = args.source_dir.join(folder).join(format!("{}.md", page));
+        if page_path.exists() {
+            println!("Keeping existing {}", page_path.display());
+            continue;
+        }
+        fs::create_dir_all(args.source_dir.join(folder))?;
+        fs::write(&page_path, content)?;
+        println!("Created {}", page_path.display());
+    }
+
+    if let Some(parent) = args.config_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&args.config_path, config)?;
+    println!("Created {}", args.config_path.display());
+
+    Ok(())
+}
@@ -1,27 +1,52 @@
+mod assets;
+mod cli;
 mod config;
+mod diagnostics;
 mod error;
 mod generator;
+mod highlight;
+mod init;
 mod models;
 mod navigation;
 mod parser;
+mod search;
+mod server;
+mod sitemap;
 mod template;
 mod utils;
+mod validate;
+mod watch;
 
 use std::env;
 
+use crate::cli::{Command, USAGE, parse_args};
 use crate::error::FrankmarkResult;
-use crate::generator::generate_site;
+use crate::generator::{check_site, clean_site, generate_site};
+use crate::init::init_site;
+use crate::server::serve_site;
+use crate::watch::watch_site;
 
-fn run(folder_path: &str) -> FrankmarkResult<()> {
-    generate_site(folder_path)
+fn run(command: Command) -> FrankmarkResult<()> {
+    match command {
+        Command::Build(args) if args.watch => watch_site(&args),
+        Command::Build(args) => generate_site(&args),
+        Command::Check(args) => check_site(&args),
+        Command::Clean(args) => clean_site(&args),
+        Command::Init(args) => init_site(&args),
+        Command::Serve(args) => serve_site(&args),
+        Command::Help => {
+            print!("{}", USAGE);
+            Ok(())
+        }
+    }
 }
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
-    let folder_path = args.get(1).map(|s| s.as_str()).unwrap_or("demo");
+    let args: Vec<String> = env::args().skip(1).collect();
 
-    if let Err(e) = run(folder_path) {
-        eprintln!("Error: {}", e);
-        std::process::exit(1);
+    if let Err(e) = parse_args(&args).and_then(run) {
+        let exit_code = e.exit_code();
+        diagnostics::print_error(e);
+        std::process::exit(exit_code);
     }
 }
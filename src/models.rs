@@ -1,63 +1,179 @@
 use crate::utils;
+use indexmap::IndexMap;
+use markdown::message::Message;
+use markdown::unist::Position;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
 use std::path::PathBuf;
 
 #[derive(Debug)]
 pub struct Folder {
     pub name: String,
+    pub path: String, // Path from the source directory, e.g. `Guides/Deployment`
     pub pages: Vec<Page>,
+    pub folders: Vec<Folder>,
 }
 
 impl Folder {
-    pub fn new(name: String) -> Self {
+    pub fn new(name: String, path: String) -> Self {
         Self {
             name,
+            path,
             pages: Vec::new(),
+            folders: Vec::new(),
         }
     }
 
     pub fn add_page(&mut self, page: Page) {
         self.pages.push(page);
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.pages.is_empty() && self.folders.is_empty()
+    }
+
+    // Whether `folder_path` is this folder or one of its subfolders
+    pub fn contains_folder(&self, folder_path: &str) -> bool {
+        folder_path == self.path
+            || folder_path
+                .strip_prefix(self.path.as_str())
+                .is_some_and(|rest| rest.starts_with('/'))
+    }
 }
 
-#[derive(Debug)]
+// Every page of the tree, depth-first: a folder's own pages come before the
+// pages of its subfolders
+pub fn all_pages(folders: &[Folder]) -> Vec<&Page> {
+    let mut pages = Vec::new();
+    for folder in folders {
+        pages.extend(&folder.pages);
+        pages.extend(all_pages(&folder.folders));
+    }
+    pages
+}
+
+// Every folder of the tree, depth-first
+pub fn all_folders(folders: &[Folder]) -> Vec<&Folder> {
+    let mut all = Vec::new();
+    for folder in folders {
+        all.push(folder);
+        all.extend(all_folders(&folder.folders));
+    }
+    all
+}
+
+#[derive(Debug, Clone)]
 pub struct Page {
+    pub source_path: PathBuf, // Path to the markdown source file
     pub output_path: PathBuf, // Path to the rendered HTML file
     pub id: String,
     pub display_name: String,
     pub content: String,
-    pub folder_name: String, // Direct reference to folder name
+    pub folder_name: String, // Path of the containing folder, e.g. `Guides/Deployment`
     pub headings: Vec<Heading>,
+    pub links: Vec<Link>,       // Link and image destinations, as written
+    pub sections: Vec<Section>, // Plain text by heading, for the search index
+    pub meta: PageMeta,
+    pub warnings: Vec<Message>, // Problems found while parsing, such as duplicate heading ids
 }
 
-#[derive(Debug)]
+// Metadata from a page's YAML (`---`) or TOML (`+++`) frontmatter
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct PageMeta {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub slug: Option<String>,
+    pub order: Option<i64>,
+    pub draft: bool,
+    pub tags: Vec<String>,
+    #[serde(flatten)]
+    pub extra: IndexMap<String, toml::Value>, // Any other key, written as meta tags
+}
+
+// `toml::Value` has no `Hash`, so `extra` values are hashed as TOML
+impl Hash for PageMeta {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.title.hash(state);
+        self.description.hash(state);
+        self.slug.hash(state);
+        self.order.hash(state);
+        self.draft.hash(state);
+        self.tags.hash(state);
+        for (key, value) in &self.extra {
+            key.hash(state);
+            value.to_string().hash(state);
+        }
+    }
+}
+
+// Why a page on disk is not part of the site
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeftOut {
+    Draft,
+    Failed, // Could not be read or parsed
+}
+
+// Pages that were found but left out, by normalized source path
+pub type LeftOutPages = HashMap<PathBuf, LeftOut>;
+
+#[derive(Debug, Clone, Hash)]
 pub struct Heading {
     pub text: String,
     pub level: u8,
     pub id: String,
 }
 
+// A link, image or definition destination, and where it is in the source
+#[derive(Debug, Clone)]
+pub struct Link {
+    pub url: String,
+    pub position: Option<Position>,
+}
+
+// The plain text under one heading of a page, or before its first heading
+// when `id` is empty
+#[derive(Debug, Clone, Default)]
+pub struct Section {
+    pub id: String,
+    pub heading: String,
+    pub text: String,
+}
+
 impl Page {
     pub fn new(
-        output_path: PathBuf,
-        full_name: String,
+        source_path: PathBuf,
         display_name: String,
         content: String,
         folder_name: String,
         headings: Vec<Heading>,
+        links: Vec<Link>,
+        meta: PageMeta,
     ) -> Self {
-        // Use deterministic ID based on content hash for better performance
-        let id = utils::generate_deterministic_id(&full_name);
+        // The source path tells pages apart even when their names do not,
+        // such as `01-intro.md` and `intro.md` in the same folder
+        let id = utils::generate_deterministic_id(&source_path.to_string_lossy());
         Self {
-            output_path,
+            source_path,
+            output_path: PathBuf::new(), // Set by the parser
             id,
             display_name,
             content,
             folder_name,
             headings,
+            links,
+            sections: Vec::new(), // Set by the parser
+            meta,
+            warnings: Vec::new(), // Set by the parser
         }
     }
 
+    // Frontmatter title, falling back to the file name
+    pub fn title(&self) -> &str {
+        self.meta.title.as_deref().unwrap_or(&self.display_name)
+    }
+
     pub fn is_active(&self, current_page: &Page) -> bool {
         self.id == current_page.id
     }
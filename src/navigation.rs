@@ -1,4 +1,4 @@
-use crate::models::{Folder, Page};
+use crate::models::{self, Folder, Page};
 use std::collections::HashMap;
 
 // Optimized navigation with pre-computed page order
@@ -9,15 +9,13 @@ pub struct PageNavigator<'a> {
 
 impl<'a> PageNavigator<'a> {
     pub fn new(folders: &'a [Folder]) -> Self {
-        let mut all_pages = Vec::new();
-        let mut page_to_index = HashMap::new();
-
-        for folder in folders {
-            for page in &folder.pages {
-                page_to_index.insert(page.id.as_str(), all_pages.len());
-                all_pages.push(page);
-            }
-        }
+        // Nested folders are flattened depth-first, in sidebar order
+        let all_pages = models::all_pages(folders);
+        let page_to_index = all_pages
+            .iter()
+            .enumerate()
+            .map(|(index, page)| (page.id.as_str(), index))
+            .collect();
 
         Self {
             all_pages,
@@ -25,6 +23,10 @@ impl<'a> PageNavigator<'a> {
         }
     }
 
+    pub fn pages(&self) -> &[&'a Page] {
+        &self.all_pages
+    }
+
     pub fn get_next_page(&self, current_page: &Page) -> Option<&'a Page> {
         let current_index = self.page_to_index.get(current_page.id.as_str())?;
         let next_index = current_index + 1;
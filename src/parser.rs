@@ -2,144 +2,706 @@ use std::{
     collections::HashMap,
     fs,
     path::{Path, PathBuf},
+    time::SystemTime,
 };
 
 use markdown::mdast::Node;
+use markdown::message::{Message, Place};
+use markdown::{FrankenUiHandler, FrankenUiOptions};
 
-use crate::config::Config;
-use crate::error::FrankmarkResult;
-use crate::models::{Folder, Heading, Page};
+use crate::assets;
+use crate::cli::SiteArgs;
+use crate::config::{Config, FolderSpec, HighlightMode};
+use crate::diagnostics::Diagnostics;
+use crate::error::{FrankmarkError, FrankmarkResult};
+use crate::highlight;
+use crate::models::{Folder, Heading, LeftOut, LeftOutPages, Link, Page, PageMeta, Section};
+use crate::utils;
 
-// Optimized directory parsing with batch operations
-pub fn parse_directory(config: &Config, config_folder_path: &str) -> FrankmarkResult<Vec<Folder>> {
-    let mut folders = Vec::new();
-    let config_folder_path = Path::new(config_folder_path);
+// Parsed pages from earlier builds, keyed by source path, so watch mode only
+// re-parses the files that changed on disk
+#[derive(Default)]
+pub struct PageCache {
+    previous: HashMap<PathBuf, (Option<SystemTime>, Page)>,
+    current: HashMap<PathBuf, (Option<SystemTime>, Page)>,
+    // Where every page of the last build was written, which is where links
+    // in cached pages point to
+    outputs: HashMap<PathBuf, PathBuf>,
+}
+
+impl PageCache {
+    fn take_unchanged(&mut self, path: &Path, modified: Option<SystemTime>) -> Option<Page> {
+        let (cached_modified, _) = self.previous.get(path)?;
+        if modified.is_some() && *cached_modified == modified {
+            self.previous.remove(path).map(|(_, page)| page)
+        } else {
+            None
+        }
+    }
+
+    fn insert(&mut self, path: PathBuf, modified: Option<SystemTime>, page: &Page) {
+        self.current.insert(path, (modified, page.clone()));
+    }
+
+    // Drop pages that were not part of this build
+    fn finish_build(&mut self) {
+        self.previous = std::mem::take(&mut self.current);
+    }
+
+    pub fn clear(&mut self) {
+        self.previous.clear();
+        self.current.clear();
+        self.outputs.clear();
+    }
+}
+
+// A page to build: either reused from the cache or parsed from disk
+struct PageJob {
+    page_name: String,
+    folder_name: String,
+    path: PathBuf,
+    output_path: PathBuf,
+    modified: Option<SystemTime>,
+    cached: Option<Page>,
+    source: Option<Result<PageSource, String>>, // Or why it could not be read
+}
+
+// A page read from disk, waiting to be parsed
+struct PageSource {
+    content: String,
+    slug: Option<String>, // Frontmatter slug, read ahead for clean URLs
+}
+
+// A folder to build, holding its pages as indices into the page jobs
+struct FolderPlan<'a> {
+    spec: &'a FolderSpec,
+    path: String,
+    pages: Vec<usize>,
+    folders: Vec<FolderPlan<'a>>,
+}
+
+// Optimized directory parsing with batch operations. Pages are parsed on
+// `args.jobs` threads, while everything that is logged or cached is handled
+// afterwards in config order, so the output does not depend on scheduling.
+pub fn parse_directory(
+    config: &Config,
+    args: &SiteArgs,
+    cache: &mut PageCache,
+    diagnostics: &mut Diagnostics,
+) -> FrankmarkResult<(Vec<Folder>, LeftOutPages)> {
+    let config_folder_path = &args.source_dir;
+    let folder_tree = config.folder_tree(config_folder_path, &args.output_dir)?;
+
+    let mut jobs = Vec::new();
+    let plans: Vec<FolderPlan> = folder_tree
+        .iter()
+        .filter_map(|spec| plan_folder(spec, "", args, cache, &mut jobs, diagnostics))
+        .collect();
+
+    // Read every page that changed. Output paths can depend on frontmatter,
+    // so they are only known once every page is read.
+    let clean_urls = config.build.clean_urls;
+    let base_path = config.book.base_path();
+    let highlight = config.build.highlight == HighlightMode::Build;
+    let frankenui = config.frankenui_options();
+    let sources = utils::parallel_map(&jobs, args.jobs, |job| {
+        job.cached.is_none().then(|| read_page(job, clean_urls))
+    });
+    for (job, source) in jobs.iter_mut().zip(sources) {
+        job.source = source;
+    }
+
+    // Cached pages link to where other pages were written, so they can only
+    // be reused when no page moved
+    let outputs = page_outputs(&jobs, args, clean_urls);
+    let mut written = HashMap::new();
+    for job in &jobs {
+        let output_path = &outputs[&utils::normalize_path(&job.path)];
+        if let Some(other) = written.insert(output_path, &job.path) {
+            diagnostics
+                .warn(
+                    &job.path,
+                    FrankmarkError::FileError(format!(
+                        "written to {} as well by {}",
+                        output_path.display(),
+                        other.display()
+                    )),
+                )
+                .hint("give one of the pages another `slug` in its frontmatter");
+        }
+    }
+    if outputs != cache.outputs {
+        for job in jobs.iter_mut().filter(|job| job.cached.is_some()) {
+            job.cached = None;
+            job.source = Some(read_page(job, clean_urls));
+        }
+        cache.outputs = outputs.clone();
+    }
+    for job in &mut jobs {
+        job.output_path = outputs[&utils::normalize_path(&job.path)].clone();
+    }
+
+    let mut parsed = utils::parallel_map(&jobs, args.jobs, |job| {
+        let source = match job.source.as_ref()? {
+            Ok(source) => source,
+            Err(reason) => return Some(Err(FrankmarkError::FileError(reason.clone()))),
+        };
+        let handler = PageHandler {
+            source_path: &job.path,
+            output_path: &job.output_path,
+            outputs: &outputs,
+            args,
+            clean_urls,
+            base_path: &base_path,
+            highlight,
+        };
+        Some(parse_page(job, source, &frankenui, &handler))
+    });
 
     // Pre-allocate capacity for better performance
-    folders.reserve(config.directories.len());
-
-    // Get all directories in the config folder once
-    let config_folder_entries: Vec<_> = fs::read_dir(config_folder_path)?
-        .filter_map(|entry| entry.ok())
-        .filter(|entry| {
-            entry
-                .metadata()
-                .map(|metadata| metadata.is_dir())
-                .unwrap_or(false)
+    let mut folders = Vec::with_capacity(plans.len());
+    let mut left_out = LeftOutPages::new();
+    for plan in &plans {
+        let folder = build_folder(
+            plan,
+            &mut jobs,
+            &mut parsed,
+            cache,
+            &mut left_out,
+            diagnostics,
+        );
+        folders.extend(folder);
+    }
+
+    cache.finish_build();
+    Ok((folders, left_out))
+}
+
+// Queue the pages of a configured folder and its subfolders, reusing the
+// previous parse of every file that has not been touched. Returns `None` when
+// the folder does not exist.
+fn plan_folder<'a>(
+    spec: &'a FolderSpec,
+    parent_path: &str,
+    args: &SiteArgs,
+    cache: &mut PageCache,
+    jobs: &mut Vec<PageJob>,
+    diagnostics: &mut Diagnostics,
+) -> Option<FolderPlan<'a>> {
+    let folder_name = if parent_path.is_empty() {
+        spec.name.clone()
+    } else {
+        format!("{}/{}", parent_path, spec.name)
+    };
+
+    let folder_path = args.source_dir.join(&folder_name);
+    if !folder_path.is_dir() {
+        // Only folders listed in `[directories]` can be missing
+        diagnostics
+            .warn(
+                folder_path,
+                FrankmarkError::DirectoryError(format!("folder '{}' does not exist", folder_name)),
+            )
+            .hint("create it, or remove it from [directories] in frankmark.toml");
+        return None;
+    }
+
+    let mut pages = Vec::with_capacity(spec.pages.len());
+    for page_name in &spec.pages {
+        let page_file_path = folder_path.join(format!("{}.md", page_name));
+
+        if !page_file_path.exists() {
+            diagnostics
+                .warn(
+                    page_file_path,
+                    FrankmarkError::FileError(format!(
+                        "page '{}' of folder '{}' does not exist",
+                        page_name, folder_name
+                    )),
+                )
+                .hint("create it, or remove it from [directories] in frankmark.toml");
+            continue;
+        }
+
+        let modified = fs::metadata(&page_file_path)
+            .and_then(|metadata| metadata.modified())
+            .ok();
+        pages.push(jobs.len());
+        jobs.push(PageJob {
+            page_name: page_name.clone(),
+            folder_name: folder_name.clone(),
+            cached: cache.take_unchanged(&page_file_path, modified),
+            source: None,
+            output_path: PathBuf::new(), // Set once every page is read
+            path: page_file_path,
+            modified,
+        });
+    }
+
+    let folders = spec
+        .folders
+        .iter()
+        .filter_map(|child| plan_folder(child, &folder_name, args, cache, jobs, diagnostics))
+        .collect();
+
+    Some(FolderPlan {
+        spec,
+        path: folder_name,
+        pages,
+        folders,
+    })
+}
+
+fn read_page(job: &PageJob, clean_urls: bool) -> Result<PageSource, String> {
+    let content = fs::read_to_string(&job.path)
+        .map_err(|e| format!("cannot read page '{}': {}", job.page_name, e))?;
+
+    let slug = if clean_urls {
+        read_frontmatter(&content)
+            .and_then(|(fence, value)| parse_meta(fence, value).ok())
+            .and_then(|meta| meta.slug)
+    } else {
+        None
+    };
+    Ok(PageSource { content, slug })
+}
+
+// Where every page is written, by source path
+fn page_outputs(jobs: &[PageJob], args: &SiteArgs, clean_urls: bool) -> HashMap<PathBuf, PathBuf> {
+    jobs.iter()
+        .map(|job| {
+            let slug = match (&job.cached, &job.source) {
+                (Some(page), _) => page.meta.slug.as_deref(),
+                (None, Some(Ok(source))) => source.slug.as_deref(),
+                (None, _) => None,
+            };
+            let output_path =
+                page_output_path(args, &job.folder_name, &job.page_name, slug, clean_urls);
+            (utils::normalize_path(&job.path), output_path)
+        })
+        .collect()
+}
+
+// `<folder>/<page>.html`, named after the source file so `.md` links map onto
+// it, or with clean URLs `<folder-slug>/<page-slug>/index.html`, where the
+// frontmatter `slug` overrides the page slug
+fn page_output_path(
+    args: &SiteArgs,
+    folder_name: &str,
+    page_name: &str,
+    slug: Option<&str>,
+    clean_urls: bool,
+) -> PathBuf {
+    if !clean_urls {
+        return args
+            .output_dir
+            .join(folder_name)
+            .join(format!("{}.html", page_name));
+    }
+
+    let mut path = args.output_dir.clone();
+    for segment in folder_name.split('/').filter(|segment| !segment.is_empty()) {
+        path.push(slug::slugify(segment));
+    }
+    let (_, display_name) = utils::split_order_prefix(page_name);
+    path.push(slug::slugify(slug.unwrap_or(display_name)));
+    path.push("index.html");
+    path
+}
+
+// Assemble a planned folder from its parsed pages, returning `None` when
+// nothing in it can be built
+fn build_folder(
+    plan: &FolderPlan,
+    jobs: &mut [PageJob],
+    parsed: &mut [Option<FrankmarkResult<Page>>],
+    cache: &mut PageCache,
+    left_out: &mut LeftOutPages,
+    diagnostics: &mut Diagnostics,
+) -> Option<Folder> {
+    let folder_name = &plan.path;
+    let mut folder = Folder::new(plan.spec.name.clone(), folder_name.clone());
+    folder.pages.reserve(plan.pages.len());
+
+    for &index in &plan.pages {
+        let job = &mut jobs[index];
+        let page = match (job.cached.take(), parsed[index].take()) {
+            (Some(page), _) => page,
+            (None, Some(Ok(page))) => page,
+            // Left out of the site, and reported with everything else
+            (None, Some(Err(e))) => {
+                diagnostics.error(&job.path, e);
+                left_out.insert(utils::normalize_path(&job.path), LeftOut::Failed);
+                continue;
+            }
+            (None, None) => unreachable!("every uncached page is parsed"),
+        };
+        cache.insert(job.path.clone(), job.modified, &page);
+        folder.add_page(page);
+    }
+
+    // Drafts stay in the cache, but are never built
+    folder.pages.retain(|page| {
+        if page.meta.draft {
+            println!("Skipping draft {}/{}", folder_name, page.display_name);
+            left_out.insert(utils::normalize_path(&page.source_path), LeftOut::Draft);
+        }
+        !page.meta.draft
+    });
+
+    // Frontmatter `order` comes first; the rest keep their file name order
+    if plan.spec.discovered {
+        folder
+            .pages
+            .sort_by_key(|page| (page.meta.order.is_none(), page.meta.order));
+    }
+
+    let page_count = folder.pages.len();
+    for child in &plan.folders {
+        if let Some(child) = build_folder(child, jobs, parsed, cache, left_out, diagnostics) {
+            folder.folders.push(child);
+        }
+    }
+
+    if folder.is_empty() {
+        return None;
+    }
+    println!("Added folder: {} with {} pages", folder_name, page_count);
+    Some(folder)
+}
+
+// The landing page, rendered as the root `index.html`, when the source
+// directory has one. Links in it point at the pages of the last
+// `parse_directory` with the same cache.
+pub fn parse_landing_page(
+    config: &Config,
+    args: &SiteArgs,
+    cache: &PageCache,
+) -> FrankmarkResult<Option<Page>> {
+    let path = args.source_dir.join(&config.build.landing_page);
+    if !path.is_file() {
+        return Ok(None);
+    }
+
+    let source = PageSource {
+        content: fs::read_to_string(&path)?,
+        slug: None,
+    };
+    let job = PageJob {
+        page_name: config.book.title.clone(),
+        folder_name: String::new(),
+        output_path: args.output_dir.join("index.html"),
+        path,
+        modified: None,
+        cached: None,
+        source: None,
+    };
+    let handler = PageHandler {
+        source_path: &job.path,
+        output_path: &job.output_path,
+        outputs: &cache.outputs,
+        args,
+        clean_urls: config.build.clean_urls,
+        base_path: &config.book.base_path(),
+        highlight: config.build.highlight == HighlightMode::Build,
+    };
+    parse_page(&job, &source, &config.frankenui_options(), &handler).map(Some)
+}
+
+// Parse a single markdown page into its HTML content and metadata
+fn parse_page(
+    job: &PageJob,
+    source: &PageSource,
+    frankenui: &FrankenUiOptions,
+    handler: &PageHandler,
+) -> FrankmarkResult<Page> {
+    let page_file_path = job.path.clone();
+
+    // One parse gives the HTML, its heading ids and the tree for metadata
+    let document = markdown::to_frankenui_document_with_handler(
+        &source.content,
+        &markdown_options(frankenui),
+        handler,
+    )
+    .map_err(FrankmarkError::MarkdownError)?;
+
+    let meta = read_meta(&document.mdast).map_err(|e| {
+        FrankmarkError::ContentError(format!(
+            "invalid frontmatter in {}: {}",
+            page_file_path.display(),
+            e
+        ))
+    })?;
+    let page_links = read_links(&document.mdast);
+    let headings: Vec<Heading> = document
+        .outline
+        .into_iter()
+        .map(|heading| Heading {
+            text: heading.text,
+            level: heading.depth,
+            id: heading.id,
         })
         .collect();
 
-    // Create a lookup map for faster directory finding
-    let mut dir_lookup: HashMap<String, PathBuf> = HashMap::new();
-    for entry in &config_folder_entries {
-        if let Ok(name) = entry.file_name().into_string() {
-            dir_lookup.insert(name, entry.path());
+    // `01-intro.md` is built as `intro`
+    let (_, display_name) = utils::split_order_prefix(&job.page_name);
+    let mut page = Page::new(
+        page_file_path,
+        display_name.to_string(),
+        document.html,
+        job.folder_name.clone(),
+        headings,
+        page_links,
+        meta,
+    );
+    page.output_path = job.output_path.clone();
+    page.sections = read_sections(&document.mdast, &page.headings);
+    page.warnings = read_duplicate_ids(&document.mdast, &page.headings);
+    Ok(page)
+}
+
+// Points links to pages at where they are written, links to other local
+// files at the copies made in the output directory, and links from the site
+// root under the base path. Highlights code unless the browser does.
+struct PageHandler<'a> {
+    source_path: &'a Path,
+    output_path: &'a Path,
+    outputs: &'a HashMap<PathBuf, PathBuf>,
+    args: &'a SiteArgs,
+    clean_urls: bool,
+    base_path: &'a str,
+    highlight: bool,
+}
+
+impl FrankenUiHandler for PageHandler<'_> {
+    fn highlight_code(&self, language: &str, code: &str) -> Option<String> {
+        if self.highlight {
+            highlight::highlight(language, code)
+        } else {
+            None
         }
     }
 
-    // Process each configured directory
-    for (folder_name, folder_pages) in &config.directories {
-        let folder_path = match dir_lookup.get(folder_name) {
-            Some(path) => path,
+    fn resolve_url(&self, url: &str) -> Option<String> {
+        if let Some(path) = url.strip_prefix('/')
+            && !path.starts_with('/')
+        {
+            return Some(format!("{}{}", self.base_path, path));
+        }
+
+        let target = utils::local_link_path(url)?;
+        let page_dir = self.output_path.parent()?;
+        // Keep the query and fragment
+        let suffix = &url[target.len()..];
+
+        let source = utils::normalize_path(&self.source_path.parent()?.join(target));
+        let output = match self.outputs.get(&source) {
+            Some(output) => output.clone(),
             None => {
-                eprintln!(
-                    "Warning: Folder '{}' not found in filesystem, skipping",
-                    folder_name
-                );
-                continue;
+                assets::local_asset(
+                    self.source_path,
+                    url,
+                    &self.args.source_dir,
+                    &self.args.output_dir,
+                )?
+                .1
             }
         };
+        Some(format!(
+            "{}{}",
+            utils::relative_url(page_dir, &output, self.clean_urls),
+            suffix
+        ))
+    }
+}
 
-        let mut folder = Folder::new(folder_name.clone());
-        folder.pages.reserve(folder_pages.len());
+// GFM plus frontmatter, so a leading `---` block is metadata rather than a
+// thematic break, and heading attributes, so `## Install {#install}` keeps
+// its anchor when its text changes
+fn markdown_options(frankenui: &FrankenUiOptions) -> markdown::Options {
+    let mut options = markdown::Options::gfm();
+    options.parse.constructs.frontmatter = true;
+    options.parse.constructs.heading_attributes = true;
+    options.compile.frankenui = frankenui.clone();
+    options
+}
 
-        // Batch read all markdown files for this folder
-        let mut page_contents = Vec::new();
-        for page_name in folder_pages {
-            let page_file_path = folder_path.join(format!("{}.md", page_name));
+// Frontmatter is always the first child of the root, when present
+pub fn read_meta(mdast: &Node) -> Result<PageMeta, String> {
+    let first = mdast.children().and_then(|children| children.first());
+    match first {
+        Some(Node::Yaml(yaml)) => parse_meta("---", &yaml.value),
+        Some(Node::Toml(toml)) => parse_meta("+++", &toml.value),
+        _ => Ok(PageMeta::default()),
+    }
+}
 
-            if !page_file_path.exists() {
-                eprintln!("Page {} not found in folder {}", page_name, folder_name);
-                continue;
-            }
+// YAML when fenced with `---`, TOML when fenced with `+++`
+fn parse_meta(fence: &str, value: &str) -> Result<PageMeta, String> {
+    if value.trim().is_empty() {
+        Ok(PageMeta::default())
+    } else if fence == "---" {
+        let mut yaml: serde_yaml::Value = serde_yaml::from_str(value).map_err(|e| e.to_string())?;
+        remove_nulls(&mut yaml);
+        serde_yaml::from_value(yaml).map_err(|e| e.to_string())
+    } else {
+        toml::from_str(value).map_err(|e| e.to_string())
+    }
+}
 
-            match fs::read_to_string(&page_file_path) {
-                Ok(content) => page_contents.push((page_name.clone(), content)),
-                Err(e) => {
-                    eprintln!(
-                        "Warning: Failed to read page '{}': {}, using default content",
-                        page_name, e
-                    );
-                    page_contents.push((page_name.clone(), format!("# {}", page_name)));
-                }
-            }
+// TOML has no null, so YAML keys and list items set to `~` or nothing are left
+// out, as if they were not given
+fn remove_nulls(value: &mut serde_yaml::Value) {
+    match value {
+        serde_yaml::Value::Mapping(mapping) => {
+            mapping.retain(|_, value| !value.is_null());
+            mapping.values_mut().for_each(remove_nulls);
+        }
+        serde_yaml::Value::Sequence(sequence) => {
+            sequence.retain(|value| !value.is_null());
+            sequence.iter_mut().for_each(remove_nulls);
         }
+        _ => {}
+    }
+}
 
-        // Process all markdown content in batch
-        for (page_name, content) in page_contents {
-            let mdast: Node = markdown::to_mdast(&content, &markdown::ParseOptions::gfm()).unwrap();
-
-            let headings = read_headings(&mdast);
-            println!("Headings for {}: {:?}", page_name, headings);
-
-            // Convert markdown to FrankenUi HTML
-            let html_content =
-                match markdown::to_html_frankenui_with_options(&content, &markdown::Options::gfm())
-                {
-                    Ok(html) => html,
-                    Err(e) => {
-                        eprintln!(
-                            "Warning: Failed to parse markdown for '{}': {}",
-                            page_name, e
-                        );
-                        format!("<h1>{}</h1>", page_name)
-                    }
-                };
-
-            let page = Page::new(
-                PathBuf::new(), // Will be set later in generate_site
-                page_name.clone(),
-                page_name,
-                html_content,
-                folder_name.clone(),
-                headings,
-            );
-            folder.add_page(page);
-        }
-
-        if !folder.pages.is_empty() {
-            let page_count = folder.pages.len();
-            folders.push(folder);
-            println!("Added folder: {} with {} pages", folder_name, page_count);
-        }
-    }
-
-    Ok(folders)
-}
-
-pub fn read_headings(mdast: &Node) -> Vec<Heading> {
-    let mut headings = Vec::new();
-    visit(&mdast, |node| {
-        if let Node::Heading(heading) = node {
-            match heading.children.first() {
-                Some(text) => {
-                    if let Node::Text(text) = text {
-                        headings.push(Heading {
-                            text: text.value.clone(),
-                            level: heading.depth,
-                            id: slug::slugify(&text.value),
-                        });
-                    } else {
-                        eprintln!("Warning: Heading has no text");
-                    }
-                }
-                None => {
-                    eprintln!("Warning: Heading has no children text");
-                }
-            }
+// The fence and value of the frontmatter at the start of a page, found
+// without parsing the rest of the markdown
+fn read_frontmatter(content: &str) -> Option<(&'static str, &str)> {
+    let fence = ["---", "+++"]
+        .into_iter()
+        .find(|fence| content.starts_with(fence))?;
+    let mut lines = content.split_inclusive('\n');
+    let start = lines.next()?.len();
+    if content[..start].trim_end() != fence {
+        return None;
+    }
+
+    let mut end = start;
+    for line in lines {
+        if line.trim_end() == fence {
+            return Some((fence, &content[start..end]));
+        }
+        end += line.len();
+    }
+    None
+}
+
+pub fn read_links(mdast: &Node) -> Vec<Link> {
+    let mut links = Vec::new();
+    visit(mdast, |node| {
+        let url = match node {
+            Node::Link(link) => &link.url,
+            Node::Image(image) => &image.url,
+            Node::Definition(definition) => &definition.url,
+            _ => return,
+        };
+        links.push(Link {
+            url: url.clone(),
+            position: node.position().cloned(),
+        });
+    });
+    links
+}
+
+// Headings whose `{#id}` an earlier heading already has, and which were
+// given a suffixed id instead. Headings come in document order, just like the
+// outline they were read from.
+fn read_duplicate_ids(mdast: &Node, headings: &[Heading]) -> Vec<Message> {
+    let mut headings = headings.iter();
+    let mut messages = Vec::new();
+    visit(mdast, |node| {
+        let Node::Heading(node) = node else {
+            return;
+        };
+        let Some(heading) = headings.next() else {
+            return;
+        };
+        if let Some(id) = node.id.as_ref().filter(|id| **id != heading.id) {
+            messages.push(Message {
+                place: node
+                    .position
+                    .clone()
+                    .map(|position| Box::new(Place::Position(position))),
+                reason: format!(
+                    "Heading id `{}` is already taken, so `{}` is used instead",
+                    id, heading.id
+                ),
+                rule_id: Box::new("duplicate-id".into()),
+                source: Box::new("frankmark".into()),
+            });
         }
     });
-    headings
+    messages
+}
+
+// Split the plain text of a page by heading, for the search index. Headings
+// come in document order, just like the outline they were read from.
+pub fn read_sections(mdast: &Node, headings: &[Heading]) -> Vec<Section> {
+    let mut sections = vec![Section::default()];
+    collect_section_text(mdast, &mut headings.iter(), &mut sections);
+
+    for section in &mut sections {
+        section.text = section
+            .text
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+    }
+    sections.retain(|section| !section.id.is_empty() || !section.text.is_empty());
+    sections
+}
+
+fn collect_section_text<'a>(
+    node: &Node,
+    headings: &mut impl Iterator<Item = &'a Heading>,
+    sections: &mut Vec<Section>,
+) {
+    let value = match node {
+        Node::Heading(_) => {
+            if let Some(heading) = headings.next() {
+                sections.push(Section {
+                    id: heading.id.clone(),
+                    heading: heading.text.clone(),
+                    text: String::new(),
+                });
+            }
+            return;
+        }
+        // Frontmatter and raw HTML are not part of the text
+        Node::Yaml(_) | Node::Toml(_) | Node::Html(_) => return,
+        Node::Text(text) => Some(&text.value),
+        Node::InlineCode(code) => Some(&code.value),
+        Node::Code(code) => Some(&code.value),
+        Node::InlineMath(math) => Some(&math.value),
+        Node::Math(math) => Some(&math.value),
+        _ => None,
+    };
+
+    // Inline text runs on, so `` `cargo`, `` stays `cargo,`, while blocks
+    // and line breaks are set apart from what comes before them
+    let inline = matches!(
+        node,
+        Node::Text(_)
+            | Node::InlineCode(_)
+            | Node::InlineMath(_)
+            | Node::Emphasis(_)
+            | Node::Strong(_)
+            | Node::Delete(_)
+            | Node::Link(_)
+            | Node::LinkReference(_)
+    );
+    if let Some(section) = sections.last_mut() {
+        if !inline {
+            section.text.push(' ');
+        }
+        if let Some(value) = value {
+            section.text.push_str(value);
+        }
+    }
+    for child in node.children().into_iter().flatten() {
+        collect_section_text(child, headings, sections);
+    }
 }
 
 /// Visit.
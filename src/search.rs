@@ -0,0 +1,67 @@
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::error::{FrankmarkError, FrankmarkResult};
+use crate::models::Page;
+use crate::utils;
+
+pub const SEARCH_INDEX_FILE: &str = "search-index.json";
+
+// Written to the root of the output directory and fetched by the search
+// dialog. Pages are listed once and sections refer to them by index, to keep
+// the file small.
+#[derive(Serialize)]
+struct SearchIndex<'a> {
+    pages: Vec<SearchPage<'a>>,
+    sections: Vec<SearchSection<'a>>,
+}
+
+#[derive(Serialize)]
+struct SearchPage<'a> {
+    url: String, // Relative to the output directory
+    title: &'a str,
+    folder: &'a str,
+}
+
+#[derive(Serialize)]
+struct SearchSection<'a> {
+    page: usize,
+    id: &'a str, // Heading anchor, empty before the first heading
+    heading: &'a str,
+    text: &'a str,
+}
+
+pub fn write_search_index(
+    pages: &[&Page],
+    output_dir: &Path,
+    clean_urls: bool,
+) -> FrankmarkResult<()> {
+    let mut index = SearchIndex {
+        pages: Vec::with_capacity(pages.len()),
+        sections: Vec::new(),
+    };
+
+    for (page_index, page) in pages.iter().enumerate() {
+        let url = utils::relative_url(output_dir, &page.output_path, clean_urls);
+        index.pages.push(SearchPage {
+            url,
+            title: page.title(),
+            folder: &page.folder_name,
+        });
+        index
+            .sections
+            .extend(page.sections.iter().map(|section| SearchSection {
+                page: page_index,
+                id: &section.id,
+                heading: &section.heading,
+                text: &section.text,
+            }));
+    }
+
+    let json = serde_json::to_string(&index).map_err(|e| {
+        FrankmarkError::ContentError(format!("cannot serialize search index: {}", e))
+    })?;
+    utils::write_if_changed(&output_dir.join(SEARCH_INDEX_FILE), &json)?;
+    Ok(())
+}
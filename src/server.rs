@@ -0,0 +1,256 @@
+use std::{
+    fs,
+    io::{BufRead, BufReader, Write},
+    net::{TcpListener, TcpStream},
+    path::{Component, Path, PathBuf},
+    sync::{Arc, Mutex},
+    thread,
+};
+
+use crate::cli::SiteArgs;
+use crate::config::parse_config;
+use crate::error::FrankmarkResult;
+use crate::generator::Site;
+use crate::watch::watch_site_with;
+
+const LIVE_RELOAD_PATH: &str = "/__frankmark/livereload";
+
+// Browsers currently listening for reload events
+type Clients = Arc<Mutex<Vec<TcpStream>>>;
+
+// Serve the output directory on localhost, rebuilding on changes and telling
+// open pages to reload after every build
+pub fn serve_site(args: &SiteArgs) -> FrankmarkResult<()> {
+    // Mounted at the base path, so links built for the real host work. It is
+    // read again after every build, as the configuration may have changed.
+    let base_path = Arc::new(Mutex::new(
+        parse_config(&args.config_path)?.book.base_path(),
+    ));
+    let listener = TcpListener::bind(("127.0.0.1", args.port))?;
+    let address = listener.local_addr()?;
+    println!("Serving on http://{}{}", address, base_path.lock().unwrap());
+
+    let clients = Clients::default();
+    let output_dir = args.output_dir.clone();
+    let server_clients = Arc::clone(&clients);
+    let server_base_path = Arc::clone(&base_path);
+    thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let output_dir = output_dir.clone();
+            let base_path = server_base_path.lock().unwrap().clone();
+            let clients = Arc::clone(&server_clients);
+            thread::spawn(move || {
+                if let Err(e) = handle_connection(stream, &output_dir, &base_path, &clients) {
+                    eprintln!("Warning: Failed to answer request: {}", e);
+                }
+            });
+        }
+    });
+
+    watch_site_with(Site::new(args).with_live_reload(), || {
+        if let Ok(config) = parse_config(&args.config_path) {
+            let next_base_path = config.book.base_path();
+            let mut base_path = base_path.lock().unwrap();
+            if *base_path != next_base_path {
+                println!("Serving on http://{}{}", address, next_base_path);
+                *base_path = next_base_path;
+            }
+        }
+        notify_reload(&clients)
+    })
+}
+
+fn notify_reload(clients: &Clients) {
+    let mut clients = clients.lock().unwrap();
+    // Drop every browser that went away since the last build
+    clients.retain_mut(|stream| {
+        stream
+            .write_all(b"event: reload\ndata: {}\n\n")
+            .and_then(|_| stream.flush())
+            .is_ok()
+    });
+}
+
+fn handle_connection(
+    mut stream: TcpStream,
+    output_dir: &Path,
+    base_path: &str,
+    clients: &Clients,
+) -> std::io::Result<()> {
+    let mut request_line = String::new();
+    let mut reader = BufReader::new(stream.try_clone()?);
+    reader.read_line(&mut request_line)?;
+
+    // Skip the headers, nothing in them changes the response
+    let mut header = String::new();
+    while reader.read_line(&mut header)? > 2 {
+        header.clear();
+    }
+
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default();
+    let target = parts.next().unwrap_or_default();
+    let path = target.split(['?', '#']).next().unwrap_or_default();
+
+    if method != "GET" && method != "HEAD" {
+        return respond(
+            &mut stream,
+            "405 Method Not Allowed",
+            "text/plain",
+            b"",
+            method,
+        );
+    }
+
+    if path == LIVE_RELOAD_PATH {
+        stream.write_all(
+            b"HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nCache-Control: no-cache\r\n\r\n",
+        )?;
+        stream.flush()?;
+        clients.lock().unwrap().push(stream);
+        return Ok(());
+    }
+
+    let site_path = match path.strip_prefix(base_path.trim_end_matches('/')) {
+        Some(rest) if rest.is_empty() || rest.starts_with('/') => Some(rest),
+        _ if path == "/" => return redirect(&mut stream, base_path),
+        _ => None,
+    };
+
+    // Relative links in `folder/page/index.html` only resolve under a path
+    // ending in `/`, so send directories there first
+    if let Some(site_path) = site_path
+        && !path.ends_with('/')
+        && let Some(dir) = resolve_dir(output_dir, site_path)
+        && dir.join("index.html").is_file()
+    {
+        return redirect(&mut stream, &format!("{}/", path));
+    }
+
+    match site_path.and_then(|site_path| resolve_file(output_dir, site_path)) {
+        Some(file) => {
+            let body = fs::read(&file)?;
+            respond(&mut stream, "200 OK", content_type(&file), &body, method)
+        }
+        None => {
+            let not_found = output_dir.join("404.html");
+            match fs::read(&not_found) {
+                Ok(body) => respond(
+                    &mut stream,
+                    "404 Not Found",
+                    "text/html; charset=utf-8",
+                    &body,
+                    method,
+                ),
+                Err(_) => respond(
+                    &mut stream,
+                    "404 Not Found",
+                    "text/plain",
+                    b"Not Found",
+                    method,
+                ),
+            }
+        }
+    }
+}
+
+fn respond(
+    stream: &mut TcpStream,
+    status: &str,
+    content_type: &str,
+    body: &[u8],
+    method: &str,
+) -> std::io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nCache-Control: no-cache\r\nConnection: close\r\n\r\n",
+        status,
+        content_type,
+        body.len()
+    )?;
+    if method != "HEAD" {
+        stream.write_all(body)?;
+    }
+    stream.flush()
+}
+
+// Temporary, so browsers do not remember redirects from an earlier site or
+// base path served on the same port
+fn redirect(stream: &mut TcpStream, location: &str) -> std::io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 302 Found\r\nLocation: {}\r\nContent-Length: 0\r\nCache-Control: no-cache\r\nConnection: close\r\n\r\n",
+        location
+    )?;
+    stream.flush()
+}
+
+// Map a request path onto a path in the output directory, refusing anything
+// that would escape it
+fn resolve_path(output_dir: &Path, request_path: &str) -> Option<PathBuf> {
+    let decoded = percent_decode(request_path)?;
+    let relative = Path::new(decoded.trim_start_matches('/'));
+    if relative
+        .components()
+        .any(|component| !matches!(component, Component::Normal(_)))
+    {
+        return None;
+    }
+    Some(output_dir.join(relative))
+}
+
+fn resolve_dir(output_dir: &Path, request_path: &str) -> Option<PathBuf> {
+    resolve_path(output_dir, request_path).filter(|path| path.is_dir())
+}
+
+fn resolve_file(output_dir: &Path, request_path: &str) -> Option<PathBuf> {
+    let mut file = resolve_path(output_dir, request_path)?;
+    if file.is_dir() {
+        file = file.join("index.html");
+    } else if !file.exists() && file.extension().is_none() {
+        // Allow extensionless links such as the ones static hosts accept
+        file.set_extension("html");
+    }
+    file.is_file().then_some(file)
+}
+
+fn percent_decode(value: &str) -> Option<String> {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        if bytes[index] == b'%' {
+            let hex = value.get(index + 1..index + 3)?;
+            decoded.push(u8::from_str_radix(hex, 16).ok()?);
+            index += 3;
+        } else {
+            decoded.push(bytes[index]);
+            index += 1;
+        }
+    }
+    String::from_utf8(decoded).ok()
+}
+
+fn content_type(path: &Path) -> &'static str {
+    let extension = path
+        .extension()
+        .map(|ext| ext.to_string_lossy().to_ascii_lowercase())
+        .unwrap_or_default();
+    match extension.as_str() {
+        "html" => "text/html; charset=utf-8",
+        "css" => "text/css; charset=utf-8",
+        "js" => "text/javascript; charset=utf-8",
+        "json" => "application/json",
+        "xml" => "application/xml",
+        "txt" => "text/plain; charset=utf-8",
+        "svg" => "image/svg+xml",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "ico" => "image/x-icon",
+        "woff2" => "font/woff2",
+        "pdf" => "application/pdf",
+        _ => "application/octet-stream",
+    }
+}
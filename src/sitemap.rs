@@ -0,0 +1,172 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+    time::SystemTime,
+};
+
+use crate::error::FrankmarkResult;
+use crate::models::Page;
+use crate::utils;
+
+pub const SITEMAP_FILE: &str = "sitemap.xml";
+
+// Absolute URL of a file in the output directory, with `index.html` left off
+pub fn absolute_url(site_url: &str, output_dir: &Path, path: &Path) -> String {
+    let relative_path = path.strip_prefix(output_dir).unwrap_or(path);
+    let url = utils::url_path(relative_path);
+    let url = url.strip_suffix("index.html").unwrap_or(&url);
+    format!("{}/{}", site_url.trim_end_matches('/'), url)
+}
+
+// Write `sitemap.xml` listing every page, and a `robots.txt` pointing at it
+// unless the static directory brings its own. Crawlers only look for
+// `/robots.txt`, so there is none for a site served under a sub-path.
+pub fn write_sitemap(
+    pages: &[&Page],
+    site_url: &str,
+    base_path: &str,
+    source_dir: &Path,
+    output_dir: &Path,
+    static_dir: &Path,
+) -> FrankmarkResult<()> {
+    let git_dates = git_last_modified(source_dir);
+
+    let mut xml = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n",
+    );
+    for page in pages {
+        xml.push_str("  <url>\n    <loc>");
+        xml.push_str(&escape_xml(&absolute_url(
+            site_url,
+            output_dir,
+            &page.output_path,
+        )));
+        xml.push_str("</loc>\n");
+        if let Some(lastmod) = last_modified(&page.source_path, source_dir, &git_dates) {
+            xml.push_str("    <lastmod>");
+            xml.push_str(&lastmod);
+            xml.push_str("</lastmod>\n");
+        }
+        xml.push_str("  </url>\n");
+    }
+    xml.push_str("</urlset>\n");
+    utils::write_if_changed(&output_dir.join(SITEMAP_FILE), &xml)?;
+
+    if base_path == "/" && !static_dir.join("robots.txt").exists() {
+        let robots = format!(
+            "User-agent: *\nAllow: /\n\nSitemap: {}/{}\n",
+            site_url.trim_end_matches('/'),
+            SITEMAP_FILE
+        );
+        utils::write_if_changed(&output_dir.join("robots.txt"), &robots)?;
+    }
+    Ok(())
+}
+
+// Date of the last commit that touched a page, falling back to the file's
+// modification time when it is not committed
+fn last_modified(
+    source_path: &Path,
+    source_dir: &Path,
+    git_dates: &HashMap<PathBuf, String>,
+) -> Option<String> {
+    let relative_path = source_path.strip_prefix(source_dir).ok()?;
+    if let Some(date) = git_dates.get(&utils::normalize_path(relative_path)) {
+        return Some(date.clone());
+    }
+
+    let modified = fs::metadata(source_path).and_then(|metadata| metadata.modified());
+    modified.ok().map(format_date)
+}
+
+// Last commit date of every committed, unmodified file under the source
+// directory. Empty when the source directory is not in a git repository.
+fn git_last_modified(source_dir: &Path) -> HashMap<PathBuf, String> {
+    let mut dates = HashMap::new();
+    let Some(log) = git(
+        source_dir,
+        &[
+            "log",
+            "--format=%x00%cI",
+            "--name-only",
+            "--relative",
+            "--",
+            ".",
+        ],
+    ) else {
+        return dates;
+    };
+
+    // Newest commits come first, so the first date seen for a file is its
+    // last modification
+    let mut date = "";
+    for line in log.lines() {
+        if let Some(commit_date) = line.strip_prefix('\0') {
+            date = commit_date;
+        } else if !line.is_empty() {
+            dates
+                .entry(utils::normalize_path(Path::new(line)))
+                .or_insert_with(|| date.to_string());
+        }
+    }
+
+    // Files with uncommitted changes fall back to their modification time
+    let changed = git(
+        source_dir,
+        &["diff", "--name-only", "--relative", "HEAD", "--", "."],
+    );
+    for line in changed.iter().flat_map(|changed| changed.lines()) {
+        dates.remove(&utils::normalize_path(Path::new(line)));
+    }
+    dates
+}
+
+fn git(dir: &Path, args: &[&str]) -> Option<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(args)
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+// `YYYY-MM-DD` in UTC
+fn format_date(time: SystemTime) -> String {
+    let seconds = time
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs());
+    let days = (seconds / 86_400) as i64;
+
+    // Civil date from days since the epoch, after Howard Hinnant's algorithm
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
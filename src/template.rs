@@ -1,5 +1,11 @@
-use crate::config::Book;
-use crate::models::{Folder, Page};
+use std::path::Path;
+
+use crate::config::{Book, HighlightMode};
+use crate::highlight::HIGHLIGHT_CSS_FILE;
+use crate::models::{self, Folder, Page};
+use crate::search::SEARCH_INDEX_FILE;
+use crate::sitemap;
+use crate::utils;
 use askama::Template;
 
 #[derive(Template)]
@@ -7,15 +13,38 @@ use askama::Template;
 pub struct MainTemplate<'a> {
     pub book: &'a Book,
     pub folders: &'a Vec<Folder>,
+    pub output_dir: &'a Path,
     pub current_page: &'a Page,
     pub previous_page: Option<&'a Page>,
     pub next_page: Option<&'a Page>,
+    pub live_reload: bool,
+    pub absolute_urls: bool, // Link from the base path, for pages served at any URL
+    pub clean_urls: bool,    // Link to pages as `folder/page/`
+    pub highlight: HighlightMode,
+    pub indexable: bool, // Whether search engines should index the page
+}
+
+// The root `index.html` when there is no landing page
+#[derive(Template)]
+#[template(path = "redirect.html")]
+pub struct RedirectTemplate<'a> {
+    pub title: &'a str,
+    pub url: String,
+}
+
+// One collapsible folder of the sidebar, rendering its subfolders recursively
+#[derive(Template)]
+#[template(path = "sidebar_folder.html")]
+pub struct SidebarFolderTemplate<'a, 'b> {
+    pub main: &'b MainTemplate<'a>,
+    pub folder: &'b Folder,
 }
 
 impl<'a> MainTemplate<'a> {
     pub fn new(
         book: &'a Book,
         folders: &'a Vec<Folder>,
+        output_dir: &'a Path,
         current_page: &'a Page,
         previous_page: Option<&'a Page>,
         next_page: Option<&'a Page>,
@@ -23,45 +52,126 @@ impl<'a> MainTemplate<'a> {
         Self {
             book,
             folders,
+            output_dir,
             current_page,
             previous_page,
             next_page,
+            live_reload: false,
+            absolute_urls: false,
+            clean_urls: false,
+            highlight: HighlightMode::default(),
+            indexable: true,
         }
     }
 
-    pub fn get_relative_path_url(&self, page: &Page) -> String {
+    // URL of a file in the output directory, from the current page
+    fn get_url(&self, path: &Path) -> String {
+        if self.absolute_urls {
+            let url = utils::relative_url(self.output_dir, path, self.clean_urls);
+            return format!("{}{}", self.book.base_path(), url.trim_start_matches("./"));
+        }
+
         // Calculate relative path from current page's directory to target page
         let current_dir = self.current_page.output_path.parent().unwrap();
-        let relative_path = pathdiff::diff_paths(&page.output_path, current_dir).unwrap();
-        relative_path.to_string_lossy().into_owned()
+        utils::relative_url(current_dir, path, self.clean_urls)
+    }
+
+    pub fn get_relative_path_url(&self, page: &Page) -> String {
+        self.get_url(&page.output_path)
+    }
+
+    // Prefix that leads from the current page to the root of the output
+    // directory, such as `../../`
+    pub fn get_root_url(&self) -> String {
+        let root = self.get_url(self.output_dir);
+        if root.is_empty() || root.ends_with('/') {
+            root
+        } else {
+            root + "/"
+        }
+    }
+
+    pub fn search_index_file(&self) -> &'static str {
+        SEARCH_INDEX_FILE
+    }
+
+    pub fn highlight_css_file(&self) -> &'static str {
+        HIGHLIGHT_CSS_FILE
+    }
+
+    // Code is highlighted by highlight.js in the browser, not at build time
+    pub fn highlights_in_browser(&self) -> bool {
+        self.highlight == HighlightMode::Client
     }
 
     pub fn get_first_page_url(&self) -> String {
-        if let Some(first_folder) = self.folders.first() {
-            if let Some(first_page) = first_folder.pages.first() {
-                return self.get_relative_path_url(first_page);
-            }
+        if let Some(first_page) = models::all_pages(self.folders).first() {
+            return self.get_relative_path_url(first_page);
         }
         String::new()
     }
 
+    pub fn render_sidebar_folder(&self, folder: &Folder) -> askama::Result<String> {
+        SidebarFolderTemplate { main: self, folder }.render()
+    }
+
     pub fn is_current_page_folder(&self, folder: &Folder) -> String {
-        if self.current_page.folder_name == folder.name {
+        if folder.contains_folder(&self.current_page.folder_name) {
             "uk-open".to_string()
         } else {
             String::new()
         }
     }
 
-    pub fn has_previous_and_next_pages(&self) -> bool {
-        self.previous_page.is_some() && self.next_page.is_some()
-    }
-
     pub fn get_page_display_name(&self, page: &Page) -> String {
         if page.folder_name == self.current_page.folder_name {
-            page.display_name.clone()
+            page.title().to_string()
         } else {
-            format!("{}/{}", page.folder_name, page.display_name)
+            format!("{}/{}", page.folder_name, page.title())
         }
     }
+
+    // Public URL of the current page, when the book has a `base_url`
+    pub fn get_canonical_url(&self) -> Option<String> {
+        let site_url = self.book.site_url()?;
+        Some(sitemap::absolute_url(
+            &site_url,
+            self.output_dir,
+            &self.current_page.output_path,
+        ))
+    }
+
+    // Page description from frontmatter, falling back to the book's
+    pub fn get_description(&self) -> Option<&str> {
+        self.current_page
+            .meta
+            .description
+            .as_deref()
+            .or(self.book.description.as_deref())
+    }
+
+    // Other frontmatter keys as `<meta>` names and contents. Lists are joined
+    // with commas, tables are left out.
+    pub fn get_extra_meta(&self) -> Vec<(&str, String)> {
+        let content = |value: &toml::Value| match value {
+            toml::Value::String(value) => Some(value.clone()),
+            toml::Value::Table(_) | toml::Value::Array(_) => None,
+            other => Some(other.to_string()),
+        };
+        self.current_page
+            .meta
+            .extra
+            .iter()
+            .filter_map(|(name, value)| {
+                let content = match value {
+                    toml::Value::Array(values) => {
+                        let values: Option<Vec<String>> = values.iter().map(content).collect();
+                        values?.join(", ")
+                    }
+                    value => content(value)?,
+                };
+                Some((name.as_str(), content))
+            })
+            .collect()
+    }
 }
@@ -1,5 +1,10 @@
 use std::collections::hash_map::DefaultHasher;
+use std::fs;
 use std::hash::{Hash, Hasher};
+use std::panic;
+use std::path::{Component, Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
 
 pub fn generate_deterministic_id(input: &str) -> String {
     let mut hasher = DefaultHasher::new();
@@ -22,3 +27,160 @@ pub fn generate_deterministic_id(input: &str) -> String {
 
     result
 }
+
+// Resolve `.` and `..` components without touching the filesystem
+pub fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    normalized.push("..");
+                }
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+// Absolute path with symlinks and `.` and `..` resolved, for comparing paths
+// that may not exist yet: the longest part that exists is canonicalized and
+// the rest is appended to it
+pub fn canonical_path(path: &Path) -> PathBuf {
+    let absolute =
+        normalize_path(&std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf()));
+    let mut existing = absolute.as_path();
+    let mut missing = Vec::new();
+    loop {
+        if let Ok(canonical) = existing.canonicalize() {
+            return missing
+                .iter()
+                .rev()
+                .fold(canonical, |path, name| path.join(name));
+        }
+        match (existing.parent(), existing.file_name()) {
+            (Some(parent), Some(name)) => {
+                missing.push(name);
+                existing = parent;
+            }
+            _ => return absolute,
+        }
+    }
+}
+
+// Write a file unless it already has these contents, so that rebuilds leave
+// unchanged files and their modification times alone
+pub fn write_if_changed(path: &Path, contents: &str) -> std::io::Result<()> {
+    if fs::read_to_string(path).ok().as_deref() != Some(contents) {
+        fs::write(path, contents)?;
+    }
+    Ok(())
+}
+
+// Strip the query and fragment off a link destination and return the local
+// file part, or `None` for external, absolute, and fragment-only links
+pub fn local_link_path(link: &str) -> Option<&str> {
+    if link.contains("://") || link.starts_with("mailto:") || link.starts_with('/') {
+        return None;
+    }
+    let end = link.find(['#', '?']).unwrap_or(link.len());
+    let path = &link[..end];
+    if path.is_empty() { None } else { Some(path) }
+}
+
+// A relative path as a URL path, with `/` separators on every platform and
+// each segment percent-encoded, so `Getting Started.html` is linked as
+// `Getting%20Started.html`
+pub fn url_path(path: &Path) -> String {
+    path.components()
+        .map(|component| encode_path_segment(&component.as_os_str().to_string_lossy()))
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+// Percent-encode everything but the characters a URL path segment may hold
+fn encode_path_segment(segment: &str) -> String {
+    let mut encoded = String::with_capacity(segment.len());
+    for byte in segment.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-._~!$&'()*+,;=:@".contains(&byte) {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    encoded
+}
+
+// URL of `to` relative to the directory `from_dir`. With clean URLs an
+// `index.html` is linked as its directory, such as `../intro/`.
+pub fn relative_url(from_dir: &Path, to: &Path, clean_urls: bool) -> String {
+    let relative_path = pathdiff::diff_paths(to, from_dir).unwrap_or_else(|| to.to_path_buf());
+    let url = url_path(&relative_path);
+    if !clean_urls {
+        return url;
+    }
+    match url.strip_suffix("index.html") {
+        Some("") => "./".to_string(),
+        Some(dir) if dir.ends_with('/') => dir.to_string(),
+        _ => url,
+    }
+}
+
+// Split a numeric ordering prefix such as `01-` or `2_` off a file name
+pub fn split_order_prefix(name: &str) -> (Option<u64>, &str) {
+    let digits = name.len() - name.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    let rest = &name[digits..];
+    match rest.strip_prefix(['-', '_']) {
+        Some(rest) if digits > 0 && !rest.is_empty() => (name[..digits].parse().ok(), rest),
+        _ => (None, name),
+    }
+}
+
+// Run `f` over `items` on up to `jobs` threads, returning the results in the
+// order of `items` however the work was scheduled
+pub fn parallel_map<T, R, F>(items: &[T], jobs: usize, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let jobs = jobs.min(items.len());
+    if jobs <= 1 {
+        return items.iter().map(f).collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let mut results: Vec<Option<R>> = items.iter().map(|_| None).collect();
+    thread::scope(|scope| {
+        let workers: Vec<_> = (0..jobs)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut done = Vec::new();
+                    loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(item) = items.get(index) else {
+                            break done;
+                        };
+                        done.push((index, f(item)));
+                    }
+                })
+            })
+            .collect();
+
+        for worker in workers {
+            let done = worker
+                .join()
+                .unwrap_or_else(|payload| panic::resume_unwind(payload));
+            for (index, result) in done {
+                results[index] = Some(result);
+            }
+        }
+    });
+
+    results
+        .into_iter()
+        .map(|result| result.expect("every item is mapped"))
+        .collect()
+}
@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use markdown::message::{Message, Place};
+
+use crate::models::{self, Folder, LeftOut, LeftOutPages, Link, Page};
+use crate::utils;
+
+// A link in a page that leads nowhere, positioned in the page's source
+#[derive(Debug)]
+pub struct BrokenLink {
+    pub source_path: PathBuf,
+    pub message: Message,
+}
+
+// Check every link, image and definition against the pages being built, their
+// heading ids, and the files on disk. `left_out` and whether pages are
+// `listed` in `[directories]` tell why a page on disk is not built.
+pub fn find_broken_links(
+    folders: &[Folder],
+    left_out: &LeftOutPages,
+    listed: bool,
+) -> Vec<BrokenLink> {
+    let pages = models::all_pages(folders);
+    let known_pages: HashMap<PathBuf, &Page> = pages
+        .iter()
+        .map(|page| (utils::normalize_path(&page.source_path), *page))
+        .collect();
+
+    let mut problems = Vec::new();
+    for page in pages {
+        for link in &page.links {
+            if let Some(message) = check_link(page, link, &known_pages, left_out, listed) {
+                problems.push(BrokenLink {
+                    source_path: page.source_path.clone(),
+                    message,
+                });
+            }
+        }
+    }
+    problems
+}
+
+fn check_link(
+    page: &Page,
+    link: &Link,
+    known_pages: &HashMap<PathBuf, &Page>,
+    left_out: &LeftOutPages,
+    listed: bool,
+) -> Option<Message> {
+    let url = link.url.as_str();
+    let fragment = url.split_once('#').map(|(_, fragment)| fragment);
+    let Some(target) = utils::local_link_path(url) else {
+        // Fragment-only links point into the page itself
+        return match fragment {
+            Some(fragment) if url.starts_with('#') => check_fragment(page, link, fragment, page),
+            _ => None,
+        };
+    };
+    let base_dir = page.source_path.parent()?;
+    let resolved = utils::normalize_path(&base_dir.join(target));
+
+    let is_markdown = resolved
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("md"));
+
+    if is_markdown {
+        match known_pages.get(&resolved) {
+            Some(target_page) => {
+                fragment.and_then(|fragment| check_fragment(page, link, fragment, target_page))
+            }
+            None if resolved.exists() => {
+                let (rule_id, reason) = match left_out.get(&resolved) {
+                    Some(LeftOut::Draft) => ("draft-page", "it is a draft"),
+                    Some(LeftOut::Failed) => ("failed-page", "it failed to build"),
+                    None if listed => ("unlisted-page", "it is not listed in frankmark.toml"),
+                    None => ("outside-page", "it is not in a folder of the site"),
+                };
+                Some(message(
+                    link,
+                    rule_id,
+                    format!("Link to `{}` is not part of the site: {}", url, reason),
+                ))
+            }
+            None => Some(message(
+                link,
+                "missing-page",
+                format!("Link to `{}` does not exist", url),
+            )),
+        }
+    } else if !resolved.exists() {
+        Some(message(
+            link,
+            "missing-file",
+            format!("`{}` does not exist", url),
+        ))
+    } else {
+        None
+    }
+}
+
+// Whether `fragment` is the id of a heading on `target`
+fn check_fragment(page: &Page, link: &Link, fragment: &str, target: &Page) -> Option<Message> {
+    if fragment.is_empty() || target.headings.iter().any(|heading| heading.id == fragment) {
+        return None;
+    }
+    let reason = if std::ptr::eq(page, target) {
+        format!("Link to `#{}` does not match any heading", fragment)
+    } else {
+        format!(
+            "Link to `{}` does not match any heading in {}",
+            link.url,
+            target.source_path.display()
+        )
+    };
+    Some(message(link, "missing-heading", reason))
+}
+
+fn message(link: &Link, rule_id: &str, reason: String) -> Message {
+    Message {
+        place: link
+            .position
+            .clone()
+            .map(|position| Box::new(Place::Position(position))),
+        reason,
+        rule_id: Box::new(rule_id.into()),
+        source: Box::new("frankmark".into()),
+    }
+}
@@ -0,0 +1,139 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::cli::SiteArgs;
+use crate::diagnostics;
+use crate::error::FrankmarkResult;
+use crate::generator::Site;
+use crate::utils;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+// Modification times of every file a build depends on
+type Snapshot = HashMap<PathBuf, Option<SystemTime>>;
+
+// Build once, then rebuild whenever a page, an asset or the configuration
+// changes.
+// Never returns unless the first snapshot of the source tree fails.
+pub fn watch_site(args: &SiteArgs) -> FrankmarkResult<()> {
+    watch_site_with(Site::new(args), || {})
+}
+
+// Like `watch_site`, for a preconfigured `Site`, calling `on_build` after
+// every build that succeeded
+pub fn watch_site_with(mut site: Site, mut on_build: impl FnMut()) -> FrankmarkResult<()> {
+    let args = site.args();
+    let mut snapshot = take_snapshot(args)?;
+
+    match site.build() {
+        Ok(()) => on_build(),
+        Err(e) => diagnostics::print_error(e),
+    }
+    println!(
+        "Watching {} for changes, press Ctrl-C to stop",
+        args.source_dir.display()
+    );
+
+    loop {
+        thread::sleep(POLL_INTERVAL);
+
+        let next_snapshot = match take_snapshot(args) {
+            Ok(next_snapshot) => next_snapshot,
+            Err(e) => {
+                eprintln!(
+                    "Warning: Failed to scan {}: {}",
+                    args.source_dir.display(),
+                    e
+                );
+                continue;
+            }
+        };
+        if next_snapshot == snapshot {
+            continue;
+        }
+
+        for path in changed_paths(&snapshot, &next_snapshot) {
+            println!("Changed {}", path.display());
+        }
+        snapshot = next_snapshot;
+
+        match site.build() {
+            Ok(()) => on_build(),
+            Err(e) => diagnostics::print_error(e),
+        }
+    }
+}
+
+fn changed_paths<'a>(before: &'a Snapshot, after: &'a Snapshot) -> Vec<&'a PathBuf> {
+    let mut changed: Vec<&PathBuf> = after
+        .iter()
+        .filter(|(path, modified)| before.get(*path) != Some(modified))
+        .map(|(path, _)| path)
+        .chain(before.keys().filter(|path| !after.contains_key(*path)))
+        .collect();
+    changed.sort();
+    changed
+}
+
+fn take_snapshot(args: &SiteArgs) -> FrankmarkResult<Snapshot> {
+    let mut snapshot = Snapshot::new();
+    snapshot.insert(args.config_path.clone(), modified_time(&args.config_path));
+    let skip = skipped_dirs(args);
+    collect_source_files(&args.source_dir, &skip, &mut snapshot)?;
+    Ok(snapshot)
+}
+
+// Directories under the source directory that no build reads: the output
+// directory, as it is reached from the source directory, and a Cargo
+// `target` directory next to the pages
+fn skipped_dirs(args: &SiteArgs) -> Vec<PathBuf> {
+    let source_dir = utils::canonical_path(&args.source_dir);
+    let output_dir = utils::canonical_path(&args.output_dir);
+    let mut skip = vec![args.source_dir.join("target")];
+    if let Ok(relative_path) = output_dir.strip_prefix(&source_dir) {
+        skip.push(args.source_dir.join(relative_path));
+    }
+    skip
+}
+
+// Collect every file under `dir` except hidden ones and the ones under the
+// `skip` directories
+fn collect_source_files(
+    dir: &Path,
+    skip: &[PathBuf],
+    snapshot: &mut Snapshot,
+) -> FrankmarkResult<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let hidden = path
+            .file_name()
+            .is_some_and(|name| name.to_string_lossy().starts_with('.'));
+
+        if hidden {
+            continue;
+        }
+
+        if path.is_dir() {
+            if skip.contains(&path) {
+                continue;
+            }
+            collect_source_files(&path, skip, snapshot)?;
+        } else {
+            // Pages, static files and the assets pages link to
+            let modified = modified_time(&path);
+            snapshot.insert(path, modified);
+        }
+    }
+    Ok(())
+}
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
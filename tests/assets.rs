@@ -0,0 +1,57 @@
+use std::fs;
+
+mod common;
+
+#[test]
+fn assets() {
+    let site = common::temp_dir("assets");
+    common::write_files(
+        &site,
+        &[
+            (
+                "frankmark.toml",
+                "[book]\ntitle = \"Assets\"\n\n[build]\nclean_urls = true\n",
+            ),
+            (
+                "Guide/01-Setup.md",
+                "# Setup\n\n![arch](img/arch.png)\n\n[manual](./manual.pdf#page=2)\n",
+            ),
+            ("Guide/img/arch.png", "png"),
+            ("Guide/manual.pdf", "pdf"),
+            ("static/css/site.css", "css"),
+        ],
+    );
+    common::build(&site, &[]);
+
+    // Static files go to the root, linked files where they are in the source
+    for (file, content) in [
+        ("css/site.css", "css"),
+        ("Guide/img/arch.png", "png"),
+        ("Guide/manual.pdf", "pdf"),
+    ] {
+        assert_eq!(
+            fs::read_to_string(site.join("output").join(file))
+                .ok()
+                .as_deref(),
+            Some(content),
+            "should copy {}",
+            file
+        );
+    }
+
+    // The page moves to `guide/setup/index.html`, so its links climb back up
+    let page = fs::read_to_string(site.join("output/guide/setup/index.html")).unwrap();
+    for expected in [
+        "src=\"../../Guide/img/arch.png\"",
+        "href=\"../../Guide/manual.pdf#page=2\"",
+    ] {
+        assert!(
+            page.contains(expected),
+            "should link to the copy with `{}`: {}",
+            expected,
+            page
+        );
+    }
+
+    fs::remove_dir_all(&site).unwrap();
+}
@@ -0,0 +1,134 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+mod common;
+use common::files;
+
+const SITE_URL: &str = "https://intranet/teams/platform/docs/";
+const BASE_PATH: &str = "/teams/platform/docs/";
+
+#[test]
+fn base_path() {
+    for clean_urls in [false, true] {
+        let site = build_demo(clean_urls);
+        let output = site.join("output");
+
+        let mut links = 0;
+        for file in files(&output) {
+            if file.extension().is_some_and(|ext| ext == "html") {
+                let html = fs::read_to_string(&file).unwrap();
+                for url in attribute_values(&html, "href=\"")
+                    .chain(attribute_values(&html, "src=\""))
+                    .chain(attribute_values(&html, "url="))
+                    .filter(|url| is_generated(url))
+                {
+                    links += 1;
+                    assert!(
+                        resolve(&output, &file, url).is_some(),
+                        "{} links to {}, which does not resolve (clean_urls = {})",
+                        file.display(),
+                        url,
+                        clean_urls
+                    );
+                }
+            }
+        }
+        assert!(links > 0, "should find links in the generated pages");
+        let not_found = fs::read_to_string(output.join("404.html")).unwrap();
+        assert!(
+            not_found.contains(&format!("href=\"{}", BASE_PATH)),
+            "should link from the base path on the 404 page"
+        );
+
+        let sitemap = fs::read_to_string(output.join("sitemap.xml")).unwrap();
+        for url in attribute_values(&sitemap, "<loc>") {
+            let path = url
+                .strip_prefix(SITE_URL)
+                .unwrap_or_else(|| panic!("sitemap url {} is not under {}", url, SITE_URL));
+            assert!(
+                resolve(&output, &output.join("index.html"), path).is_some(),
+                "sitemap url {} does not resolve",
+                url
+            );
+        }
+
+        // The search dialog prefixes these with the URL of the site root
+        let search_index = fs::read_to_string(output.join("search-index.json")).unwrap();
+        for url in attribute_values(&search_index, "\"url\":\"") {
+            assert!(
+                resolve(&output, &output.join("index.html"), url).is_some(),
+                "search index url {} does not resolve",
+                url
+            );
+        }
+
+        fs::remove_dir_all(&site).unwrap();
+    }
+}
+
+// Copy the demo to a temporary directory, serve it under `BASE_PATH`, and
+// build it
+fn build_demo(clean_urls: bool) -> PathBuf {
+    let site = common::temp_dir(&format!("base-path-{}", clean_urls));
+    common::copy_demo(&site);
+
+    let config_path = site.join("frankmark.toml");
+    let config = fs::read_to_string(&config_path).unwrap();
+    let mut config: String = config
+        .lines()
+        .map(|line| {
+            if line.starts_with("base_url") {
+                format!("base_url = \"{}\"\n", SITE_URL)
+            } else {
+                format!("{}\n", line)
+            }
+        })
+        .collect();
+    config.push_str(&format!("\n[build]\nclean_urls = {}\n", clean_urls));
+    fs::write(&config_path, config).unwrap();
+
+    common::build(&site, &[]);
+    site
+}
+
+// Values following `prefix` up to the next `"` or `<`
+fn attribute_values<'a>(text: &'a str, prefix: &'a str) -> impl Iterator<Item = &'a str> {
+    text.match_indices(prefix).map(move |(index, _)| {
+        let value = &text[index + prefix.len()..];
+        &value[..value.find(['"', '<']).unwrap_or(value.len())]
+    })
+}
+
+// Links frankmark writes: to pages, and anything from the site root. Links
+// the demo pages spell out themselves are left to `frankmark check`.
+fn is_generated(url: &str) -> bool {
+    let path = &url[..url.find(['#', '?']).unwrap_or(url.len())];
+    path.starts_with('/') || path.ends_with(".html") || path.ends_with('/')
+}
+
+// The output file a link in `from` leads to, as a static host serving the
+// output directory under `BASE_PATH` would find it. External links and
+// fragments resolve to `from` itself.
+fn resolve(output: &Path, from: &Path, url: &str) -> Option<PathBuf> {
+    if url.is_empty()
+        || url.starts_with('#')
+        || url.starts_with("//")
+        || url.contains("://")
+        || url.starts_with("mailto:")
+        || url.starts_with("data:")
+    {
+        return Some(from.to_path_buf());
+    }
+
+    let path = &url[..url.find(['#', '?']).unwrap_or(url.len())];
+    let mut target = match path.strip_prefix('/') {
+        Some(_) => output.join(path.strip_prefix(BASE_PATH)?),
+        None => from.parent()?.join(path),
+    };
+    if target.is_dir() {
+        target.push("index.html");
+    }
+    target.is_file().then_some(target)
+}
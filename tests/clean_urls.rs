@@ -0,0 +1,88 @@
+use std::{fs, path::PathBuf};
+
+mod common;
+
+#[test]
+fn clean_urls() {
+    let site = common::temp_dir("clean-urls");
+    common::write_files(
+        &site,
+        &[
+            (
+                "frankmark.toml",
+                "[book]\ntitle = \"Clean\"\n\n[build]\nclean_urls = true\n",
+            ),
+            (
+                "Getting Started/01-Setup.md",
+                "# Setup\n\n[Deploy](02-Deploy.md#ship-it)\n",
+            ),
+            (
+                "Getting Started/02-Deploy.md",
+                "---\nslug: ship\n---\n# Ship it\n",
+            ),
+        ],
+    );
+    common::build(&site, &[]);
+
+    // Slugified folder and page names, or the page's own slug, as directories
+    let output = site.join("output");
+    let files: Vec<_> = common::files(&output)
+        .into_iter()
+        .map(|file| file.strip_prefix(&output).unwrap().to_path_buf())
+        .collect();
+    assert_eq!(
+        files,
+        [
+            "404.html",
+            "getting-started/setup/index.html",
+            "getting-started/ship/index.html",
+            "highlight.css",
+            "index.html",
+            "search-index.json",
+        ]
+        .map(PathBuf::from),
+        "should write every page as the index of its own directory"
+    );
+
+    // Links point at directories, never at `index.html`
+    let setup = fs::read_to_string(output.join("getting-started/setup/index.html")).unwrap();
+    for expected in [
+        "href=\"../ship/#ship-it\"",
+        "<li class=\"uk-active\"><a href=\"./\">",
+        "<a href=\"../ship/\" class=\"uk-btn uk-btn-md",
+        "href=\"../../highlight.css\"",
+    ] {
+        assert!(
+            setup.contains(expected),
+            "should link with `{}`: {}",
+            expected,
+            setup
+        );
+    }
+    assert!(
+        !setup.contains("index.html\""),
+        "should not link to index.html: {}",
+        setup
+    );
+
+    let index = fs::read_to_string(output.join("index.html")).unwrap();
+    assert!(
+        index.contains("url=getting-started/setup/\""),
+        "should redirect the root to the first page's directory: {}",
+        index
+    );
+    let search_index = fs::read_to_string(output.join("search-index.json")).unwrap();
+    assert!(
+        search_index.contains("\"url\":\"getting-started/ship/\""),
+        "should index pages by their directory: {}",
+        search_index
+    );
+    let not_found = fs::read_to_string(output.join("404.html")).unwrap();
+    assert!(
+        not_found.contains("href=\"/getting-started/ship/\""),
+        "should link from the root on the 404 page: {}",
+        not_found
+    );
+
+    fs::remove_dir_all(&site).unwrap();
+}
@@ -0,0 +1,127 @@
+// Fixtures shared by the integration tests. Each test crate uses only some of
+// them.
+#![allow(dead_code)]
+
+use std::{
+    ffi::OsStr,
+    fs,
+    path::{Path, PathBuf},
+    process::{Child, Command, Output, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+// An empty directory under the system temp directory, unique to this test
+// process
+pub fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("frankmark-{}-{}", name, std::process::id()));
+    if dir.exists() {
+        fs::remove_dir_all(&dir).unwrap();
+    }
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+// Copy the demo sources, without their output, to `site`
+pub fn copy_demo(site: &Path) {
+    let demo = Path::new(env!("CARGO_MANIFEST_DIR")).join("demo");
+    for file in files(&demo) {
+        let relative_path = file.strip_prefix(&demo).unwrap();
+        if relative_path.starts_with("output") {
+            continue;
+        }
+        let target = site.join(relative_path);
+        fs::create_dir_all(target.parent().unwrap()).unwrap();
+        fs::copy(&file, &target).unwrap();
+    }
+}
+
+// Write each `(path, content)` under `site`, creating directories as needed
+pub fn write_files(site: &Path, files: &[(&str, &str)]) {
+    for (path, content) in files {
+        let path = site.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+}
+
+// Every file under a directory, recursively
+pub fn files(dir: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            files.extend(self::files(&path));
+        } else {
+            files.push(path);
+        }
+    }
+    files.sort();
+    files
+}
+
+// Run the frankmark binary
+pub fn frankmark<I, S>(args: I) -> Output
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<OsStr>,
+{
+    Command::new(env!("CARGO_BIN_EXE_frankmark"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+// `frankmark build <site>` with extra arguments, which must succeed
+pub fn build(site: &Path, args: &[&str]) -> Output {
+    let output = frankmark(
+        [OsStr::new("build"), site.as_os_str()]
+            .into_iter()
+            .chain(args.iter().map(OsStr::new)),
+    );
+    assert!(
+        output.status.success(),
+        "build failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    output
+}
+
+// A frankmark command that keeps running, such as `serve` or `build --watch`.
+// It is killed when dropped, even when the test fails.
+pub struct Running(Child);
+
+impl Drop for Running {
+    fn drop(&mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
+
+// Start the frankmark binary without waiting for it
+pub fn spawn<I, S>(args: I) -> Running
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<OsStr>,
+{
+    Running(
+        Command::new(env!("CARGO_BIN_EXE_frankmark"))
+            .args(args)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .unwrap(),
+    )
+}
+
+// Poll `done` until it holds, for at most 20 seconds
+pub fn wait_until(what: &str, mut done: impl FnMut() -> bool) {
+    let start = Instant::now();
+    while start.elapsed() < Duration::from_secs(20) {
+        if done() {
+            return;
+        }
+        thread::sleep(Duration::from_millis(100));
+    }
+    panic!("timed out waiting until {}", what);
+}
@@ -0,0 +1,72 @@
+use std::fs;
+
+mod common;
+
+#[test]
+fn discovery() {
+    let site = common::temp_dir("discovery");
+    common::write_files(
+        &site,
+        &[
+            ("frankmark.toml", "[book]\ntitle = \"Discovery\"\n"),
+            ("Guide/b.md", "# B\n"),
+            ("Guide/a.md", "# A\n"),
+            ("Guide/10-deploy.md", "# Deploy\n"),
+            ("Guide/02-setup.md", "# Setup\n"),
+            ("Guide/late.md", "---\norder: 1\n---\n# Late\n"),
+            ("Guide/01-intro.md", "# Intro\n"),
+            ("Guide/intro.md", "# Another intro\n"),
+            (".drafts/hidden.md", "# Hidden\n"),
+            ("static/Assets/asset.md", "# Asset\n"),
+            ("output/Old/old.md", "# Old\n"),
+        ],
+    );
+
+    // Frontmatter `order` first, then numeric prefixes, then alphabetically
+    let output = common::frankmark(["check".as_ref(), site.as_os_str()]);
+    assert!(
+        String::from_utf8_lossy(&output.stdout).contains("Checked 7 pages"),
+        "should skip hidden, static and output directories: {}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+    common::build(&site, &[]);
+    let index = fs::read_to_string(site.join("output/search-index.json")).unwrap();
+    let urls: Vec<&str> = index
+        .split("\"url\":\"")
+        .skip(1)
+        .map(|rest| rest.split('"').next().unwrap())
+        .collect();
+    assert_eq!(
+        urls,
+        [
+            "Guide/late.html",
+            "Guide/01-intro.html",
+            "Guide/02-setup.html",
+            "Guide/10-deploy.html",
+            "Guide/a.html",
+            "Guide/b.html",
+            "Guide/intro.html",
+        ],
+        "should order pages by `order`, then prefix, then name"
+    );
+    assert!(
+        !site.join("output/.drafts").exists() && !site.join("output/Assets/asset.html").exists(),
+        "should not build pages of skipped directories"
+    );
+
+    // Pages whose names only differ by their prefix are told apart
+    let intro = fs::read_to_string(site.join("output/Guide/01-intro.html")).unwrap();
+    assert_eq!(
+        intro.matches("class=\"uk-active\"").count(),
+        1,
+        "should only mark the current page as active: {}",
+        intro
+    );
+    let another_intro = fs::read_to_string(site.join("output/Guide/intro.html")).unwrap();
+    assert!(
+        another_intro.contains("<li class=\"uk-active\"><a href=\"intro.html\">"),
+        "should mark the page itself as active, not its namesake"
+    );
+
+    fs::remove_dir_all(&site).unwrap();
+}
@@ -0,0 +1,65 @@
+use std::{fs, path::Path};
+
+mod common;
+
+#[test]
+fn exit_codes() {
+    let site = common::temp_dir("exit-codes");
+    common::write_files(
+        &site,
+        &[
+            (
+                "frankmark.toml",
+                "[book]\ntitle = \"Exit codes\"\n\n[directories]\nGuide = [\"a\", \"b\"]\n",
+            ),
+            ("Guide/a.md", "# A\n"),
+        ],
+    );
+
+    // A page listed in `[directories]` that does not exist only warns
+    assert_eq!(
+        run(&site, &["build"]),
+        Some(0),
+        "should only warn about a missing page"
+    );
+    assert_eq!(
+        run(&site, &["build", "--deny-warnings"]),
+        Some(3),
+        "should fail with the IO code on a missing page when warnings are denied"
+    );
+    assert_eq!(
+        run(&site, &["check", "--deny-warnings"]),
+        Some(3),
+        "should fail the check with the IO code on a missing page"
+    );
+
+    // A page that cannot be read
+    fs::create_dir_all(site.join("Guide/b.md")).unwrap();
+    assert_eq!(
+        run(&site, &["build"]),
+        Some(3),
+        "should fail with the IO code on a page that cannot be read"
+    );
+    fs::remove_dir_all(site.join("Guide/b.md")).unwrap();
+
+    // Frontmatter that cannot be parsed
+    fs::write(site.join("Guide/b.md"), "---\ntitle: [b\n---\n# B\n").unwrap();
+    assert_eq!(
+        run(&site, &["build"]),
+        Some(4),
+        "should fail with the content code on bad frontmatter"
+    );
+
+    fs::remove_dir_all(&site).unwrap();
+}
+
+// Run the command `args[0]` on `site` with the rest of `args`, returning the
+// exit code
+fn run(site: &Path, args: &[&str]) -> Option<i32> {
+    let output = common::frankmark(
+        [args[0].as_ref(), site.as_os_str()]
+            .into_iter()
+            .chain(args[1..].iter().map(|arg| arg.as_ref())),
+    );
+    output.status.code()
+}
@@ -0,0 +1,76 @@
+use std::fs;
+
+mod common;
+
+#[test]
+fn frontmatter() {
+    let site = common::temp_dir("frontmatter");
+    common::write_files(
+        &site,
+        &[
+            ("frankmark.toml", "[book]\ntitle = \"Book\"\n"),
+            (
+                "Guide/yaml.md",
+                "---\n\
+                 title: Deploying\n\
+                 description: How to ship it\n\
+                 tags: [ops, kubernetes]\n\
+                 audience: operators\n\
+                 reviewed: ~\n\
+                 levels: [1, ~, 2]\n\
+                 nested:\n  key: value\n\
+                 ---\n\
+                 # Deploy\n",
+            ),
+            (
+                "Guide/toml.md",
+                "+++\n\
+                 title = \"Configuring\"\n\
+                 audience = \"admins\"\n\
+                 beta = true\n\
+                 updated = 2024-05-01\n\
+                 +++\n\
+                 # Configure\n",
+            ),
+        ],
+    );
+    common::build(&site, &[]);
+
+    let yaml = fs::read_to_string(site.join("output/Guide/yaml.html")).unwrap();
+    for expected in [
+        "<title>Deploying - Book</title>",
+        "<meta name=\"description\" content=\"How to ship it\">",
+        "<meta name=\"keywords\" content=\"ops, kubernetes\">",
+        "<meta name=\"audience\" content=\"operators\">",
+        "<meta name=\"levels\" content=\"1, 2\">",
+    ] {
+        assert!(
+            yaml.contains(expected),
+            "should write `{}` from YAML frontmatter",
+            expected
+        );
+    }
+    for unexpected in ["name=\"reviewed\"", "name=\"nested\""] {
+        assert!(
+            !yaml.contains(unexpected),
+            "should leave out null and nested YAML keys, found `{}`",
+            unexpected
+        );
+    }
+
+    let toml = fs::read_to_string(site.join("output/Guide/toml.html")).unwrap();
+    for expected in [
+        "<title>Configuring - Book</title>",
+        "<meta name=\"audience\" content=\"admins\">",
+        "<meta name=\"beta\" content=\"true\">",
+        "<meta name=\"updated\" content=\"2024-05-01\">",
+    ] {
+        assert!(
+            toml.contains(expected),
+            "should write `{}` from TOML frontmatter",
+            expected
+        );
+    }
+
+    fs::remove_dir_all(&site).unwrap();
+}
@@ -0,0 +1,80 @@
+use std::fs;
+
+mod common;
+
+const PAGE: &str = "# Code\n\n```rust\nfn main() {}\n```\n\n```nosuchlang\nplain <b>\n```\n";
+
+#[test]
+fn highlight() {
+    let site = common::temp_dir("highlight");
+    common::write_files(
+        &site,
+        &[
+            ("frankmark.toml", "[book]\ntitle = \"Highlight\"\n"),
+            ("Guide/code.md", PAGE),
+        ],
+    );
+    common::build(&site, &[]);
+
+    // Highlighted while building, as spans styled by `highlight.css`
+    let page = fs::read_to_string(site.join("output/Guide/code.html")).unwrap();
+    for expected in [
+        "<code class=\"language-rust\"><span class=\"hl-source hl-rust\">",
+        "<span class=\"hl-storage hl-type hl-function hl-rust\">fn</span>",
+        "<code class=\"language-nosuchlang\">plain &lt;b&gt;\n</code>",
+        "<link rel=\"stylesheet\" href=\"../highlight.css\">",
+    ] {
+        assert!(
+            page.contains(expected),
+            "should highlight with `{}`: {}",
+            expected,
+            page
+        );
+    }
+    assert!(
+        !page.contains("highlight.min.js"),
+        "should not load highlight.js when highlighting while building"
+    );
+    let css = fs::read_to_string(site.join("output/highlight.css")).unwrap();
+    for expected in [
+        ":root:not(.dark) .hl-code {",
+        ".dark .hl-code {",
+        ".hl-storage",
+    ] {
+        assert!(
+            css.contains(expected),
+            "should style spans in light and dark mode with `{}`",
+            expected
+        );
+    }
+
+    // Highlighted in the browser, by highlight.js
+    common::write_files(
+        &site,
+        &[(
+            "frankmark.toml",
+            "[book]\ntitle = \"Highlight\"\n\n[build]\nhighlight = \"client\"\n",
+        )],
+    );
+    common::build(&site, &[]);
+    let page = fs::read_to_string(site.join("output/Guide/code.html")).unwrap();
+    for expected in [
+        "<code class=\"language-rust\">fn main() {}\n</code>",
+        "/highlight.min.js\"></script>",
+        "/languages/rust.min.js\"></script>",
+        "<script>hljs.highlightAll();</script>",
+    ] {
+        assert!(
+            page.contains(expected),
+            "should leave highlighting to the browser with `{}`: {}",
+            expected,
+            page
+        );
+    }
+    assert!(
+        !page.contains("hl-rust") && !site.join("output/highlight.css").exists(),
+        "should neither highlight nor style spans when the browser highlights"
+    );
+
+    fs::remove_dir_all(&site).unwrap();
+}
@@ -0,0 +1,37 @@
+use std::fs;
+
+mod common;
+
+#[test]
+fn jobs() {
+    let root = common::temp_dir("jobs");
+    let site = root.join("site");
+    common::copy_demo(&site);
+
+    // Pages are rendered in parallel, but the output must not depend on how
+    // many threads render them
+    let outputs = [root.join("jobs-1"), root.join("jobs-8")];
+    for (output, jobs) in outputs.iter().zip(["1", "8"]) {
+        common::build(&site, &["--out", output.to_str().unwrap(), "--jobs", jobs]);
+    }
+    let [one, eight] = outputs.each_ref().map(|output| {
+        common::files(output)
+            .into_iter()
+            .map(|file| file.strip_prefix(output).unwrap().to_path_buf())
+            .collect::<Vec<_>>()
+    });
+    assert!(!one.is_empty(), "should build the demo");
+    assert_eq!(
+        one, eight,
+        "should write the same files with one job as with eight"
+    );
+    for file in &one {
+        assert!(
+            fs::read(outputs[0].join(file)).unwrap() == fs::read(outputs[1].join(file)).unwrap(),
+            "should write the same {} with one job as with eight",
+            file.display()
+        );
+    }
+
+    fs::remove_dir_all(&root).unwrap();
+}
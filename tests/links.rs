@@ -0,0 +1,213 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+mod common;
+
+#[test]
+fn links() {
+    let site = create_site();
+
+    // Links that resolve, one per kind
+    for (link, kind) in [
+        ("[b](b.md)", "a page"),
+        ("[b](b.md#b-heading)", "a heading on another page"),
+        ("[a](#a-heading)", "a heading on the same page"),
+        ("![logo](logo.png)", "a file that is not a page"),
+        (
+            "[c](../Reference/c.md#c-heading)",
+            "a page in another folder",
+        ),
+        (
+            "[x](https://example.com/missing.md#nope)",
+            "an external URL",
+        ),
+    ] {
+        let (code, stderr) = check(&site, link);
+        assert_eq!(
+            code,
+            Some(0),
+            "should accept a link to {}: {}",
+            kind,
+            stderr
+        );
+    }
+
+    // Links that lead nowhere, and what they are reported as
+    for (link, kind, report) in [
+        (
+            "[x](missing.md)",
+            "a missing page",
+            "error: Markdown error: Link to `missing.md` does not exist (frankmark:missing-page)",
+        ),
+        (
+            "[b](b.md#nope)",
+            "a missing heading on another page",
+            "error: Markdown error: Link to `b.md#nope` does not match any heading in",
+        ),
+        (
+            "[a](#nope)",
+            "a missing heading on the same page",
+            "error: Markdown error: Link to `#nope` does not match any heading (frankmark:missing-heading)",
+        ),
+        (
+            "![logo](missing.png)",
+            "a missing file",
+            "error: Markdown error: `missing.png` does not exist (frankmark:missing-file)",
+        ),
+        (
+            "[c](../Reference/missing.md)",
+            "a missing page in another folder",
+            "error: Markdown error: Link to `../Reference/missing.md` does not exist (frankmark:missing-page)",
+        ),
+    ] {
+        let (code, stderr) = check(&site, link);
+        assert_eq!(
+            code,
+            Some(4),
+            "should fail on a link to {}: {}",
+            kind,
+            stderr
+        );
+        assert!(
+            stderr.contains(report),
+            "should report a link to {} as `{}`: {}",
+            kind,
+            report,
+            stderr
+        );
+        assert!(
+            stderr.contains("a.md:3:1"),
+            "should report where a link to {} is: {}",
+            kind,
+            stderr
+        );
+    }
+
+    // Anchors taken twice are suffixed, which links to the second one would
+    // not expect
+    let (code, stderr) = check(&site, "# B heading {#a-heading}");
+    assert_eq!(
+        code,
+        Some(0),
+        "should only warn about a duplicate heading id: {}",
+        stderr
+    );
+    assert!(
+        stderr.contains(
+            "warning: Markdown error: Heading id `a-heading` is already taken, so `a-heading-1` is used instead (frankmark:duplicate-id)"
+        ),
+        "should report a duplicate heading id: {}",
+        stderr
+    );
+
+    fs::remove_dir_all(&site).unwrap();
+}
+
+#[test]
+fn links_to_left_out_pages() {
+    let site = common::temp_dir("links-left-out");
+    common::write_files(
+        &site,
+        &[
+            ("frankmark.toml", "[book]\ntitle = \"Links\"\n"),
+            ("Guide/a.md", "# A heading\n"),
+            ("Guide/draft.md", "---\ndraft: true\n---\n# Draft\n"),
+            ("Guide/broken.md", "---\ntitle: [broken\n---\n# Broken\n"),
+            ("top.md", "# Top\n"),
+        ],
+    );
+
+    // Pages on disk that are not built, and why
+    for (link, report, hint) in [
+        (
+            "[d](draft.md)",
+            "Link to `draft.md` is not part of the site: it is a draft (frankmark:draft-page)",
+            "publish the page by removing `draft: true` from it",
+        ),
+        (
+            "[b](broken.md)",
+            "Link to `broken.md` is not part of the site: it failed to build (frankmark:failed-page)",
+            "fix the errors reported for the page",
+        ),
+        (
+            "[t](../top.md)",
+            "Link to `../top.md` is not part of the site: it is not in a folder of the site (frankmark:outside-page)",
+            "only pages in a folder of the source directory are built",
+        ),
+    ] {
+        let (_, stderr) = check(&site, link);
+        for expected in [report, hint] {
+            assert!(
+                stderr.contains(expected),
+                "should report `{}` with `{}`: {}",
+                link,
+                expected,
+                stderr
+            );
+        }
+        assert!(
+            !stderr.contains("[directories]"),
+            "should not mention [directories] when there is none: {}",
+            stderr
+        );
+    }
+
+    // With `[directories]`, pages not listed there are not built either
+    common::write_files(
+        &site,
+        &[(
+            "frankmark.toml",
+            "[book]\ntitle = \"Links\"\n\n[directories]\nGuide = [\"a\", \"b\"]\n",
+        )],
+    );
+    let (_, stderr) = check(&site, "[t](../top.md)");
+    for expected in [
+        "Link to `../top.md` is not part of the site: it is not listed in frankmark.toml (frankmark:unlisted-page)",
+        "add the page to [directories] in frankmark.toml",
+        "page 'b' of folder 'Guide' does not exist",
+        "create it, or remove it from [directories] in frankmark.toml",
+    ] {
+        assert!(
+            stderr.contains(expected),
+            "should report `{}` with [directories]: {}",
+            expected,
+            stderr
+        );
+    }
+
+    fs::remove_dir_all(&site).unwrap();
+}
+
+// A site with two folders, `Guide` and `Reference`, whose pages have one
+// heading each
+fn create_site() -> PathBuf {
+    let site = common::temp_dir("links");
+    common::write_files(
+        &site,
+        &[
+            ("frankmark.toml", "[book]\ntitle = \"Links\"\n"),
+            ("Guide/a.md", "# A heading\n"),
+            ("Guide/b.md", "# B heading\n"),
+            ("Guide/logo.png", ""),
+            ("Reference/c.md", "# C heading\n"),
+        ],
+    );
+    site
+}
+
+// Put `link` on the third line of `Guide/a.md` and check the site, returning
+// the exit code and what was reported
+fn check(site: &Path, link: &str) -> (Option<i32>, String) {
+    fs::write(
+        site.join("Guide/a.md"),
+        format!("# A heading\n\n{}\n", link),
+    )
+    .unwrap();
+    let output = common::frankmark(["check".as_ref(), site.as_os_str()]);
+    (
+        output.status.code(),
+        String::from_utf8_lossy(&output.stderr).into_owned(),
+    )
+}
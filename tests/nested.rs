@@ -0,0 +1,89 @@
+use std::fs;
+
+mod common;
+
+const CONFIG: &str = r#"[book]
+title = "Nested"
+
+[directories]
+"Guides/Deployment" = ["Docker"]
+Reference = ["Api"]
+
+[directories.Guides]
+"." = ["Overview"]
+"#;
+
+#[test]
+fn nested() {
+    let site = common::temp_dir("nested");
+    common::write_files(
+        &site,
+        &[
+            ("frankmark.toml", CONFIG),
+            ("Guides/Overview.md", "# Overview\n"),
+            ("Guides/Deployment/Docker.md", "# Docker\n"),
+            ("Reference/Api.md", "# Api\n"),
+        ],
+    );
+    common::build(&site, &[]);
+
+    // A folder's pages come before its subfolders, which are nested in it
+    let docker = fs::read_to_string(site.join("output/Guides/Deployment/Docker.html")).unwrap();
+    assert_eq!(
+        docker.matches("<ul class=\"uk-nav-sub\"").count(),
+        3,
+        "should give every folder its own sub-list: {}",
+        docker
+    );
+    let positions: Vec<usize> = [
+        "Guides <span",
+        "<a href=\"../Overview.html\">Overview</a>",
+        "Deployment <span",
+        "<li class=\"uk-active\"><a href=\"Docker.html\">Docker</a></li>",
+        "Reference <span",
+        "<a href=\"../../Reference/Api.html\">Api</a>",
+    ]
+    .iter()
+    .map(|entry| {
+        docker
+            .find(entry)
+            .unwrap_or_else(|| panic!("should list `{}` in the sidebar", entry))
+    })
+    .collect();
+    assert!(
+        positions.is_sorted(),
+        "should list the sidebar depth-first: {}",
+        docker
+    );
+
+    // Previous and next pages follow the same order, across folders
+    for (page, previous, next) in [
+        ("Guides/Overview.html", None, Some("Deployment/Docker.html")),
+        (
+            "Guides/Deployment/Docker.html",
+            Some("../Overview.html"),
+            Some("../../Reference/Api.html"),
+        ),
+        (
+            "Reference/Api.html",
+            Some("../Guides/Deployment/Docker.html"),
+            None,
+        ),
+    ] {
+        let html = fs::read_to_string(site.join("output").join(page)).unwrap();
+        let links: Vec<&str> = html
+            .split("<a href=\"")
+            .skip(1)
+            .filter(|link| link.split('>').next().unwrap().contains("uk-btn-md"))
+            .map(|link| link.split('"').next().unwrap())
+            .collect();
+        let expected: Vec<&str> = previous.into_iter().chain(next).collect();
+        assert_eq!(
+            links, expected,
+            "should link {} to its previous and next pages",
+            page
+        );
+    }
+
+    fs::remove_dir_all(&site).unwrap();
+}
@@ -0,0 +1,47 @@
+use std::fs;
+
+mod common;
+use common::files;
+
+#[test]
+fn output_dir() {
+    // A test that fails to refuse `site/..` only removes `root`
+    let root = common::temp_dir("output-dir");
+    let site = root.join("site");
+    common::copy_demo(&site);
+    fs::create_dir_all(site.join("static")).unwrap();
+    let page_count = files(&site).len();
+
+    // The source directory itself, a directory around it, and its static
+    // directory
+    let outputs = [site.clone(), site.join(".."), site.join("static")];
+    for command in ["build", "clean"] {
+        for output in &outputs {
+            let result = common::frankmark([
+                command.as_ref(),
+                site.as_os_str(),
+                "--out".as_ref(),
+                output.as_os_str(),
+            ]);
+            assert_eq!(
+                result.status.code(),
+                Some(2),
+                "`{}` should refuse to use {} as output directory",
+                command,
+                output.display()
+            );
+            assert_eq!(
+                files(&site).len(),
+                page_count,
+                "`{}` should not remove anything with {} as output directory",
+                command,
+                output.display()
+            );
+        }
+    }
+
+    // The default output directory is fine
+    common::build(&site, &[]);
+
+    fs::remove_dir_all(&root).unwrap();
+}
@@ -0,0 +1,69 @@
+use std::fs;
+
+mod common;
+
+#[test]
+fn root_pages() {
+    let site = common::temp_dir("root-pages");
+    common::write_files(
+        &site,
+        &[
+            ("frankmark.toml", "[book]\ntitle = \"Root\"\n"),
+            ("Guide/02-usage.md", "# Usage\n"),
+            ("Guide/01-install.md", "# Install\n"),
+        ],
+    );
+    common::build(&site, &[]);
+
+    // Without a landing page, the root redirects to the first page
+    let index = fs::read_to_string(site.join("output/index.html")).unwrap();
+    assert!(
+        index.contains("<meta http-equiv=\"refresh\" content=\"0; url=Guide/01-install.html\">"),
+        "should redirect the root to the first page: {}",
+        index
+    );
+
+    // The 404 page is served for any URL, so it links from the site root
+    let not_found = fs::read_to_string(site.join("output/404.html")).unwrap();
+    for expected in [
+        "<title>Page not found - Root</title>",
+        "<meta name=\"robots\" content=\"noindex\">",
+        "<h1 class=\"uk-h1 mt-8 mb-4\">Page not found</h1>",
+        "<a href=\"/Guide/02-usage.html\">",
+    ] {
+        assert!(
+            not_found.contains(expected),
+            "should write `{}` to the 404 page: {}",
+            expected,
+            not_found
+        );
+    }
+
+    // An `index.md` next to the configuration is rendered as the root
+    common::write_files(
+        &site,
+        &[(
+            "index.md",
+            "# Welcome\n\n[Start here](Guide/01-install.md)\n",
+        )],
+    );
+    common::build(&site, &[]);
+    let index = fs::read_to_string(site.join("output/index.html")).unwrap();
+    for expected in [
+        "id=\"welcome\">Welcome</h1>",
+        "href=\"Guide/01-install.html\">Start here</a>",
+    ] {
+        assert!(
+            index.contains(expected),
+            "should render the landing page with `{}`: {}",
+            expected,
+            index
+        );
+    }
+    assert!(
+        !index.contains("http-equiv=\"refresh\""),
+        "should not redirect from the landing page"
+    );
+
+    fs::remove_dir_all(&site).unwrap();
+}
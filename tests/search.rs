@@ -0,0 +1,54 @@
+use std::fs;
+
+use serde_json::json;
+
+mod common;
+
+#[test]
+fn search() {
+    let site = common::temp_dir("search");
+    common::write_files(
+        &site,
+        &[
+            ("frankmark.toml", "[book]\ntitle = \"Search\"\n"),
+            (
+                "Guide/setup.md",
+                "---\ntitle: Setting up\n---\n\
+                 Before any heading.\n\n\
+                 # Install\n\n\
+                 Run `cargo install`, then *check* it. <kbd>Raw</kbd>\n\n\
+                 ## Configure\n\n\
+                 ```toml\n[book]\n```\n\n\
+                 - one\n- two\n",
+            ),
+            ("Guide/deploy.md", "# Deploy\n"),
+        ],
+    );
+    common::build(&site, &[]);
+
+    // Pages in navigation order, and the plain text under each heading
+    let index = fs::read_to_string(site.join("output/search-index.json")).unwrap();
+    let index: serde_json::Value = serde_json::from_str(&index).unwrap();
+    assert_eq!(
+        index,
+        json!({
+            "pages": [
+                {"url": "Guide/deploy.html", "title": "deploy", "folder": "Guide"},
+                {"url": "Guide/setup.html", "title": "Setting up", "folder": "Guide"},
+            ],
+            "sections": [
+                {"page": 0, "id": "deploy", "heading": "Deploy", "text": ""},
+                {"page": 1, "id": "", "heading": "", "text": "Before any heading."},
+                {
+                    "page": 1,
+                    "id": "install",
+                    "heading": "Install",
+                    "text": "Run cargo install, then check it. Raw",
+                },
+                {"page": 1, "id": "configure", "heading": "Configure", "text": "[book] one two"},
+            ],
+        })
+    );
+
+    fs::remove_dir_all(&site).unwrap();
+}
@@ -0,0 +1,69 @@
+use std::{
+    fs,
+    io::{Read, Write},
+    net::{TcpListener, TcpStream},
+};
+
+mod common;
+
+const CONFIG: &str = "[book]\ntitle = \"Serve\"\nbase_path = \"/docs/\"\n";
+
+#[test]
+fn serve() {
+    let site = common::temp_dir("serve");
+    common::write_files(
+        &site,
+        &[("frankmark.toml", CONFIG), ("Guide/a.md", "# A\n")],
+    );
+
+    let port = TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    let _server = common::spawn([
+        "serve".as_ref(),
+        site.as_os_str(),
+        "--port".as_ref(),
+        port.to_string().as_ref(),
+    ]);
+
+    let response = wait_for(port, |response| response.contains("Location: /docs/"));
+    assert!(
+        response.starts_with("HTTP/1.1 302 Found"),
+        "should redirect the root to the base path temporarily: {}",
+        response
+    );
+
+    fs::write(
+        site.join("frankmark.toml"),
+        CONFIG.replace("/docs/", "/manual/"),
+    )
+    .unwrap();
+    let response = wait_for(port, |response| response.contains("Location: /manual/"));
+    assert!(
+        response.starts_with("HTTP/1.1 302 Found"),
+        "should redirect the root to the new base path: {}",
+        response
+    );
+
+    fs::remove_dir_all(&site).unwrap();
+}
+
+// Request `/` until the response passes `done`
+fn wait_for(port: u16, done: impl Fn(&str) -> bool) -> String {
+    let mut response = String::new();
+    common::wait_until("the server answers as expected", || {
+        response = get(port, "/").unwrap_or_default();
+        done(&response)
+    });
+    response
+}
+
+fn get(port: u16, path: &str) -> std::io::Result<String> {
+    let mut stream = TcpStream::connect(("127.0.0.1", port))?;
+    write!(stream, "GET {} HTTP/1.1\r\nHost: localhost\r\n\r\n", path)?;
+    let mut response = String::new();
+    stream.read_to_string(&mut response)?;
+    Ok(response)
+}
@@ -0,0 +1,93 @@
+use std::{fs, path::Path, process::Command};
+
+mod common;
+
+const CONFIG: &str = "[book]\ntitle = \"Sitemap\"\nbase_url = \"https://docs.example.com\"\n";
+
+#[test]
+fn sitemap() {
+    let site = common::temp_dir("sitemap");
+    common::write_files(
+        &site,
+        &[
+            ("frankmark.toml", CONFIG),
+            ("Guide/Getting Started.md", "# Getting started\n"),
+            ("Guide/b.md", "# B\n"),
+        ],
+    );
+
+    // Both pages are committed, then one of them is changed again
+    git(&site, &["init", "--quiet"]);
+    git(&site, &["add", "."]);
+    git(&site, &["commit", "--quiet", "--message", "Pages"]);
+    fs::write(site.join("Guide/b.md"), "# B, changed\n").unwrap();
+    common::build(&site, &[]);
+
+    let sitemap = fs::read_to_string(site.join("output/sitemap.xml")).unwrap();
+    assert!(
+        sitemap.contains(
+            "<loc>https://docs.example.com/Guide/Getting%20Started.html</loc>\n    \
+             <lastmod>2020-01-02T03:04:05+00:00</lastmod>"
+        ),
+        "should date a committed page by its last commit, with its URL encoded: {}",
+        sitemap
+    );
+    let changed = sitemap
+        .split("<loc>https://docs.example.com/Guide/b.html</loc>\n    <lastmod>")
+        .nth(1)
+        .expect("should list the changed page with a date");
+    assert!(
+        !changed.starts_with("2020-01-02") && changed.as_bytes()[4] == b'-',
+        "should date a page with uncommitted changes by its modification time: {}",
+        sitemap
+    );
+
+    assert_eq!(
+        fs::read_to_string(site.join("output/robots.txt")).unwrap(),
+        "User-agent: *\nAllow: /\n\nSitemap: https://docs.example.com/sitemap.xml\n",
+        "should point robots.txt at the sitemap"
+    );
+
+    let page = fs::read_to_string(site.join("output/Guide/Getting Started.html")).unwrap();
+    assert!(
+        page.contains(
+            "<link rel=\"canonical\" href=\"https://docs.example.com/Guide/Getting%20Started.html\">"
+        ),
+        "should link the page to its encoded canonical URL: {}",
+        page
+    );
+
+    // Crawlers do not look for robots.txt under a sub-path
+    fs::write(
+        site.join("frankmark.toml"),
+        CONFIG.replace(".com\"", ".com/docs/\""),
+    )
+    .unwrap();
+    common::build(&site, &[]);
+    let sitemap = fs::read_to_string(site.join("output/sitemap.xml")).unwrap();
+    assert!(
+        sitemap.contains("<loc>https://docs.example.com/docs/Guide/b.html</loc>"),
+        "should list pages under the sub-path: {}",
+        sitemap
+    );
+    assert!(
+        !site.join("output/robots.txt").exists(),
+        "should not write robots.txt under a sub-path"
+    );
+
+    fs::remove_dir_all(&site).unwrap();
+}
+
+// Run git in `dir` with a fixed author and date
+fn git(dir: &Path, args: &[&str]) {
+    let status = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+        .args(args)
+        .env("GIT_AUTHOR_DATE", "2020-01-02T03:04:05Z")
+        .env("GIT_COMMITTER_DATE", "2020-01-02T03:04:05Z")
+        .status()
+        .unwrap();
+    assert!(status.success(), "git {:?} failed", args);
+}
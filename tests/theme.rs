@@ -0,0 +1,57 @@
+use std::fs;
+
+mod common;
+
+#[test]
+fn theme_classes() {
+    let site = common::temp_dir("theme");
+    common::write_files(
+        &site,
+        &[
+            (
+                "frankmark.toml",
+                "[book]\ntitle = \"Theme\"\n\n\
+                 [theme.classes]\nheading_2 = \"uk-h2 mt-12\"\nparagraph = \"\"\n",
+            ),
+            ("Guide/a.md", "## Install\n\nText\n"),
+        ],
+    );
+    common::build(&site, &[]);
+
+    // Classes by their snake_case names, with the rule after `<h2>` that
+    // frankmark adds by default
+    let page = fs::read_to_string(site.join("output/Guide/a.html")).unwrap();
+    for expected in [
+        "<h2 class=\"uk-h2 mt-12\" id=\"install\">Install</h2>\n<hr class=\"uk-hr mb-6\"/>",
+        "<p>Text</p>",
+    ] {
+        assert!(
+            page.contains(expected),
+            "should write `{}`: {}",
+            expected,
+            page
+        );
+    }
+
+    // The camelCase names markdown-rs serializes are not elements here
+    for element in ["heading2", "unknown"] {
+        fs::write(
+            site.join("frankmark.toml"),
+            format!(
+                "[book]\ntitle = \"Theme\"\n\n[theme.classes]\n{} = \"x\"\n",
+                element
+            ),
+        )
+        .unwrap();
+        let output = common::frankmark(["build".as_ref(), site.as_os_str()]);
+        assert_eq!(
+            output.status.code(),
+            Some(2),
+            "should refuse `{}` as an element: {}",
+            element,
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    fs::remove_dir_all(&site).unwrap();
+}
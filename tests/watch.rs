@@ -0,0 +1,73 @@
+use std::{collections::HashMap, fs, path::Path, time::SystemTime};
+
+mod common;
+
+#[test]
+fn watch() {
+    let site = common::temp_dir("watch");
+    common::write_files(
+        &site,
+        &[
+            ("frankmark.toml", "[book]\ntitle = \"Watch\"\n"),
+            ("Guide/a.md", "# A\n\nFirst draft.\n"),
+            ("Guide/b.md", "# B\n\n![logo](logo.png)\n"),
+            ("Guide/logo.png", "png"),
+        ],
+    );
+    let output = site.join("output");
+    let _watch = common::spawn(["build".as_ref(), site.as_os_str(), "--watch".as_ref()]);
+    common::wait_until("the site is built", || {
+        output.join("Guide/logo.png").exists()
+    });
+
+    // Only the edited page, and the search index holding its text, are
+    // written again
+    let before = modified_times(&output);
+    fs::write(site.join("Guide/a.md"), "# A\n\nSecond draft.\n").unwrap();
+    common::wait_until("the edited page is indexed", || {
+        read(&output.join("search-index.json")).contains("Second draft.")
+    });
+    let after = modified_times(&output);
+    let mut changed: Vec<_> = after
+        .keys()
+        .filter(|path| before.get(*path) != after.get(*path))
+        .map(|path| path.as_str())
+        .collect();
+    changed.sort();
+    assert_eq!(
+        changed,
+        ["Guide/a.html", "search-index.json"],
+        "should only write what the edit changed"
+    );
+
+    // A page deleted from the output is written again with the next change
+    fs::remove_file(output.join("Guide/b.html")).unwrap();
+    fs::write(site.join("Guide/a.md"), "# A\n\nThird draft.\n").unwrap();
+    common::wait_until("the deleted page is written again", || {
+        output.join("Guide/b.html").exists()
+    });
+
+    // A copied asset goes once its source is deleted
+    fs::remove_file(site.join("Guide/logo.png")).unwrap();
+    common::wait_until("the deleted asset is removed", || {
+        !output.join("Guide/logo.png").exists()
+    });
+
+    fs::remove_dir_all(&site).unwrap();
+}
+
+fn read(path: &Path) -> String {
+    fs::read_to_string(path).unwrap_or_default()
+}
+
+// Modification time of every file under `dir`, by path relative to it
+fn modified_times(dir: &Path) -> HashMap<String, SystemTime> {
+    common::files(dir)
+        .into_iter()
+        .map(|file| {
+            let modified = fs::metadata(&file).unwrap().modified().unwrap();
+            let relative_path = file.strip_prefix(dir).unwrap();
+            (relative_path.to_string_lossy().into_owned(), modified)
+        })
+        .collect()
+}